@@ -0,0 +1,182 @@
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use tycho_types::abi::{Function, NamedAbiValue};
+use tycho_vm::VmGetterMethodId;
+
+use super::blockchain_context::{BlockchainAccount, BlockchainContext};
+use super::function_ext::{ExecutionOutput, RunLocalOptions};
+use super::stack::{GetterError, StackArgs, StackResult};
+use crate::error::ExecutionError;
+
+/// Runs local calls and getters for many accounts on a pool of threads.
+///
+/// Results are returned in the order of accounts. The config is parsed once
+/// per batch from the runner context, so accounts are expected to be
+/// fetched with the same context.
+pub struct BatchRunner<'a> {
+    context: &'a BlockchainContext,
+    workers: NonZeroUsize,
+}
+
+impl<'a> BatchRunner<'a> {
+    /// Creates a runner with one worker per available CPU.
+    pub fn new(context: &'a BlockchainContext) -> Self {
+        Self {
+            context,
+            workers: std::thread::available_parallelism().unwrap_or(NonZeroUsize::MIN),
+        }
+    }
+
+    pub fn with_workers(mut self, workers: NonZeroUsize) -> Self {
+        self.workers = workers;
+        self
+    }
+
+    /// Runs the function for each account with an external message.
+    pub fn run_local(
+        &self,
+        accounts: &[BlockchainAccount],
+        function: &Function,
+        values: &[NamedAbiValue],
+        options: &RunLocalOptions<'_>,
+    ) -> Result<Vec<Result<ExecutionOutput, ExecutionError>>, ExecutionError> {
+        self.run_local_impl(accounts, options, |account, options| {
+            account.run_local_ext(function, values, options)
+        })
+    }
+
+    /// Runs the responsible function for each account.
+    pub fn run_local_responsible(
+        &self,
+        accounts: &[BlockchainAccount],
+        function: &Function,
+        values: &[NamedAbiValue],
+        options: &RunLocalOptions<'_>,
+    ) -> Result<Vec<Result<ExecutionOutput, ExecutionError>>, ExecutionError> {
+        self.run_local_impl(accounts, options, |account, options| {
+            account.run_local_responsible_ext(function, values, options)
+        })
+    }
+
+    /// Runs the getter for each account.
+    ///
+    /// Arguments are converted to stack values separately for each account.
+    pub fn run_getter_typed<M, A, R>(
+        &self,
+        accounts: &[BlockchainAccount],
+        method_id: &M,
+        args: A,
+    ) -> Vec<Result<R, GetterError>>
+    where
+        M: VmGetterMethodId + Sync + ?Sized,
+        A: StackArgs + Clone + Sync,
+        R: StackResult + Send,
+    {
+        self.map(accounts, |account| {
+            account.run_getter_typed(method_id, args.clone())
+        })
+    }
+
+    /// Calls `f` for each account on the worker pool.
+    pub fn map<T, F>(&self, accounts: &[BlockchainAccount], f: F) -> Vec<T>
+    where
+        T: Send,
+        F: Fn(&BlockchainAccount) -> T + Sync,
+    {
+        parallel_map(self.workers, accounts, f)
+    }
+
+    fn run_local_impl<F>(
+        &self,
+        accounts: &[BlockchainAccount],
+        options: &RunLocalOptions<'_>,
+        f: F,
+    ) -> Result<Vec<Result<ExecutionOutput, ExecutionError>>, ExecutionError>
+    where
+        F: Fn(&BlockchainAccount, &RunLocalOptions<'_>) -> Result<ExecutionOutput, ExecutionError>
+            + Sync,
+    {
+        let parsed;
+        let mut options = *options;
+        if options.parsed_config.is_none() {
+            let utime = options
+                .overrides
+                .and_then(|overrides| overrides.block_utime)
+                .unwrap_or_else(|| self.context.clock().now_sec_u64() as u32);
+            parsed = self.context.parsed_config(utime)?;
+            options.parsed_config = Some(parsed.as_ref());
+        }
+
+        Ok(self.map(accounts, |account| f(account, &options)))
+    }
+}
+
+fn parallel_map<I, T, F>(workers: NonZeroUsize, items: &[I], f: F) -> Vec<T>
+where
+    I: Sync,
+    T: Send,
+    F: Fn(&I) -> T + Sync,
+{
+    let workers = workers.get().min(items.len());
+    if workers <= 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next = &AtomicUsize::new(0);
+    let f = &f;
+
+    std::thread::scope(|scope| {
+        let handles = (0..workers)
+            .map(|_| {
+                scope.spawn(move || {
+                    let mut done = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(index) else {
+                            break;
+                        };
+                        done.push((index, f(item)));
+                    }
+                    done
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut results = Vec::with_capacity(items.len());
+        results.resize_with(items.len(), || None);
+        for handle in handles {
+            let done = match handle.join() {
+                Ok(done) => done,
+                Err(e) => std::panic::resume_unwind(e),
+            };
+            for (index, result) in done {
+                results[index] = Some(result);
+            }
+        }
+
+        results
+            .into_iter()
+            .map(|result| result.expect("all items are processed"))
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parallel_map_keeps_order() {
+        let items = (0..1000u32).collect::<Vec<_>>();
+        for workers in [1, 3, 8] {
+            let workers = NonZeroUsize::new(workers).unwrap();
+            let results = parallel_map(workers, &items, |item| item * 2);
+            assert_eq!(
+                results,
+                items.iter().map(|item| item * 2).collect::<Vec<_>>()
+            );
+        }
+        assert!(parallel_map(NonZeroUsize::MIN, &[] as &[u32], |item| *item).is_empty());
+    }
+}
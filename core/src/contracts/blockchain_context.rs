@@ -1,61 +1,147 @@
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
 
-use crate::error::ExecutionError;
-use crate::models::ContractState;
+use crate::error::{AbiErrors, ExecutionError};
+use crate::models::{ContractState, GenTimings, LastTransactionId};
 use crate::transport::Transport;
 use nekoton_utils::time::{Clock, SimpleClock};
-use tycho_executor::ExecutorParams;
-use tycho_types::abi::{Function, NamedAbiValue};
+use num_bigint::BigInt;
+use tycho_executor::{ExecutorParams, ParsedConfig};
+use tycho_types::abi::{Contract, Function, NamedAbiValue};
+use tycho_types::boc::Boc;
+use tycho_types::cell::HashBytes;
 use tycho_types::models::{
-    Account, BlockchainConfig, ExtInMsgInfo, IntAddr, IntMsgInfo, MsgInfo, OwnedMessage, StdAddr,
-    Transaction,
+    Account, AccountState, AccountStatus, BlockchainConfig, CurrencyCollection, ExtAddr,
+    ExtInMsgInfo, ExtOutMsgInfo, IntAddr, IntMsgInfo, MsgInfo, OptionalAccount, OwnedMessage,
+    ShardAccount, StateInit, StdAddr, Transaction,
 };
-use tycho_types::prelude::{Cell, CellBuilder, CellFamily, DynCell};
+use tycho_types::prelude::{Cell, CellBuilder, CellFamily, DynCell, Lazy};
 use tycho_vm::{
-    OwnedCellSlice, RcStackValue, VmCaller, VmGetterError, VmGetterMethodId, VmGetterOutput,
+    GasParams, OwnedCellSlice, RcStackValue, SmcInfoBase, VmCaller, VmGetterError,
+    VmGetterMethodId, VmGetterOutput, VmState,
 };
 
-use super::function_ext::{ExecutionOutput, FunctionExt};
+use super::config_cache::ParsedConfigCache;
+use super::fork::ForkedChain;
+use super::function_ext::{ExecutionOutput, FunctionExt, RunLocalOptions};
+use super::local_executor::{self, TransactionFees};
+use super::overrides::StateOverrides;
+use super::stack::{GetterError, StackArgs, StackResult};
+use super::storage;
+use super::tracer::ExecutionTrace;
+use super::utils::get_gen_timings;
 
 #[derive(Clone)]
 pub struct BlockchainContext {
     desc: BlockchainDesc,
+    config: Arc<RwLock<ConfigSnapshot>>,
     transport: Arc<dyn Transport>,
     clock: Arc<dyn Clock>,
+    parsed_config: ParsedConfigCache,
 }
 
-impl BlockchainContext {
-    pub async fn get_account(self, address: &StdAddr) -> anyhow::Result<BlockchainAccount> {
-        let state = self.transport.get_contract_state(address, None).await?;
-        let account = match state {
-            ContractState::Exists { account, .. } => account,
-            ContractState::NotExists { .. } => anyhow::bail!("Account does not exist"),
-            _ => unreachable!(),
-        };
+struct ConfigSnapshot {
+    seqno: Option<u32>,
+    config: Arc<BlockchainConfig>,
+}
 
-        Ok(BlockchainAccount {
-            context: self,
-            account: account.as_ref().clone(),
-        })
+impl BlockchainContext {
+    /// Fetches the account state from the transport.
+    ///
+    /// A missing account is returned as an uninit one with
+    /// [`AccountStatus::NotExists`] status.
+    pub async fn get_account(&self, address: &StdAddr) -> anyhow::Result<BlockchainAccount> {
+        let mut account = BlockchainAccount::new(self.clone(), empty_account(address));
+        account.exists = false;
+        account.refresh().await?;
+        Ok(account)
     }
 
     pub fn get_account_from_cell(
-        self,
+        &self,
         account_cell: &DynCell,
     ) -> anyhow::Result<BlockchainAccount> {
         let account = account_cell.parse::<Account>()?;
-        Ok(BlockchainAccount {
-            context: self,
-            account,
-        })
+        Ok(BlockchainAccount::new(self.clone(), account))
+    }
+
+    /// Creates a local fork of the chain on top of the transport.
+    pub fn fork(&self) -> ForkedChain {
+        ForkedChain::new(self.clone())
+    }
+
+    pub(crate) fn transport(&self) -> &dyn Transport {
+        self.transport.as_ref()
+    }
+
+    /// Same context with requests served by another transport.
+    pub(crate) fn with_transport(&self, transport: Arc<dyn Transport>) -> Self {
+        Self {
+            transport,
+            ..self.clone()
+        }
     }
 
     pub fn clock(&self) -> &dyn Clock {
         self.clock.as_ref()
     }
 
-    pub fn config(&self) -> &BlockchainConfig {
-        &self.desc.config
+    /// Returns the current config. It may be replaced later by
+    /// [`set_config`](Self::set_config) or the config refresher.
+    pub fn config(&self) -> Arc<BlockchainConfig> {
+        self.config.read().unwrap().config.clone()
+    }
+
+    /// Seqno of the key block with the current config, if it was fetched
+    /// from the transport.
+    pub fn config_seqno(&self) -> Option<u32> {
+        self.config.read().unwrap().seqno
+    }
+
+    /// Replaces the config for this context and all its clones.
+    pub fn set_config(&self, config: BlockchainConfig, seqno: Option<u32>) {
+        *self.config.write().unwrap() = ConfigSnapshot {
+            seqno,
+            config: Arc::new(config),
+        };
+        self.parsed_config.clear();
+    }
+
+    /// Fetches the latest config from the transport.
+    ///
+    /// The config is replaced only with one from a newer key block,
+    /// so a lagging node can't roll it back. Returns `true` if the config
+    /// has been replaced.
+    pub async fn refresh_config(&self) -> anyhow::Result<bool> {
+        let latest = self.transport.get_config().await?;
+        if matches!(self.config_seqno(), Some(seqno) if latest.seqno <= seqno) {
+            return Ok(false);
+        }
+        self.set_config(latest.config, Some(latest.seqno));
+        Ok(true)
+    }
+
+    /// Spawns a task which refreshes the config with the specified interval.
+    ///
+    /// Must be called within a Tokio runtime. The task is stopped when
+    /// the returned handle is dropped.
+    pub fn spawn_config_refresher(&self, interval: Duration) -> ConfigRefresher {
+        let context = self.clone();
+        let handle = tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                if let Err(e) = context.refresh_config().await {
+                    tracing::warn!("failed to refresh blockchain config: {e:?}");
+                }
+            }
+        });
+        ConfigRefresher { handle }
+    }
+
+    /// Returns the config parsed for the block time, reusing the cached one
+    /// while it stays valid.
+    pub fn parsed_config(&self, utime: u32) -> Result<Arc<ParsedConfig>, ExecutionError> {
+        self.parsed_config.get(&self.config(), utime)
     }
 
     pub fn executor_params(&self) -> &ExecutorParams {
@@ -70,33 +156,171 @@ impl BlockchainContext {
 pub struct BlockchainAccount {
     context: BlockchainContext,
     account: Account,
+    exists: bool,
+    timings: GenTimings,
+    last_transaction_id: Option<LastTransactionId>,
+}
+
+/// Result of a locally executed transaction.
+#[derive(Debug, Clone)]
+pub struct EmulatedTransaction {
+    pub transaction: Transaction,
+    /// Account state after the transaction, `None` if it was deleted.
+    pub account: Option<Account>,
+}
+
+impl EmulatedTransaction {
+    /// Returns an error if any phase of the transaction has failed.
+    pub fn check(&self, errors: Option<&AbiErrors>) -> Result<(), ExecutionError> {
+        ExecutionError::check_transaction(&self.transaction, errors)
+    }
 }
 
 #[derive(Clone)]
 pub struct BlockchainDesc {
-    pub config: BlockchainConfig,
     pub executor_params: ExecutorParams,
 }
 
+/// Handle of the background config refresher, aborts the task on drop.
+pub struct ConfigRefresher {
+    handle: tokio::task::JoinHandle<()>,
+}
+
+impl Drop for ConfigRefresher {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
 impl BlockchainAccount {
     pub fn new(context: BlockchainContext, account: Account) -> Self {
-        Self { context, account }
+        let timings = GenTimings {
+            gen_lt: account.last_trans_lt,
+            gen_utime: 0,
+        };
+        Self {
+            context,
+            account,
+            exists: true,
+            timings,
+            last_transaction_id: None,
+        }
+    }
+
+    pub fn account(&self) -> &Account {
+        &self.account
+    }
+
+    pub fn status(&self) -> AccountStatus {
+        if !self.exists {
+            return AccountStatus::NotExists;
+        }
+        match &self.account.state {
+            AccountState::Uninit => AccountStatus::Uninit,
+            AccountState::Active(_) => AccountStatus::Active,
+            AccountState::Frozen(_) => AccountStatus::Frozen,
+        }
+    }
+
+    /// Decodes the persistent fields declared by the ABI.
+    pub fn decode_storage(
+        &self,
+        contract: &Contract,
+    ) -> Result<Vec<NamedAbiValue>, ExecutionError> {
+        storage::decode_storage(&self.account, contract)
+    }
+
+    /// Timings of the block in which the state was fetched.
+    pub fn timings(&self) -> &GenTimings {
+        &self.timings
+    }
+
+    pub fn last_transaction_id(&self) -> Option<&LastTransactionId> {
+        self.last_transaction_id.as_ref()
+    }
+
+    /// Fetches the latest account state from the transport.
+    ///
+    /// Returns `true` if the state has changed since the last refresh.
+    pub async fn refresh(&mut self) -> anyhow::Result<bool> {
+        let IntAddr::Std(address) = &self.account.address else {
+            anyhow::bail!("unsupported address type");
+        };
+
+        let last_transaction_lt = self.last_transaction_id.map(|id| id.lt);
+        let state = self
+            .context
+            .transport
+            .get_contract_state(address, last_transaction_lt)
+            .await?;
+
+        match state {
+            ContractState::Unchanged { timings } => {
+                anyhow::ensure!(
+                    last_transaction_lt.is_some(),
+                    "unexpected unchanged state for a new account"
+                );
+                self.timings = timings;
+                Ok(false)
+            }
+            ContractState::NotExists { timings } => {
+                let changed = self.exists;
+                self.account = empty_account(address);
+                self.exists = false;
+                self.timings = timings;
+                self.last_transaction_id = None;
+                Ok(changed)
+            }
+            ContractState::Exists {
+                account,
+                timings,
+                last_transaction_id,
+            } => {
+                let changed = self.last_transaction_id != Some(last_transaction_id);
+                self.account = *account;
+                self.exists = true;
+                self.timings = timings;
+                self.last_transaction_id = Some(last_transaction_id);
+                Ok(changed)
+            }
+        }
     }
 
     pub fn run_local(
-        &mut self,
+        &self,
+        function: &Function,
+        values: &[NamedAbiValue],
+    ) -> Result<ExecutionOutput, ExecutionError> {
+        self.run_local_ext(function, values, &Default::default())
+    }
+
+    /// Runs the function with an external message built from the options.
+    pub fn run_local_ext(
+        &self,
         function: &Function,
         values: &[NamedAbiValue],
+        options: &RunLocalOptions<'_>,
     ) -> Result<ExecutionOutput, ExecutionError> {
-        function.run_local(&mut self.account, values, false, &mut self.context)
+        function.run_local(&self.account, values, false, options, &self.context)
     }
 
     pub fn run_local_responsible(
-        &mut self,
+        &self,
+        function: &Function,
+        values: &[NamedAbiValue],
+    ) -> Result<ExecutionOutput, ExecutionError> {
+        self.run_local_responsible_ext(function, values, &Default::default())
+    }
+
+    /// Runs the responsible function with the specified overrides and tracing.
+    /// Message signing options are not used.
+    pub fn run_local_responsible_ext(
+        &self,
         function: &Function,
         values: &[NamedAbiValue],
+        options: &RunLocalOptions<'_>,
     ) -> Result<ExecutionOutput, ExecutionError> {
-        function.run_local(&mut self.account, values, true, &mut self.context)
+        function.run_local(&self.account, values, true, options, &self.context)
     }
 
     pub async fn execute_message(
@@ -110,6 +334,82 @@ impl BlockchainAccount {
             .map_err(Into::into)
     }
 
+    /// Emulates the transaction for the message and returns the fees
+    /// without changing the account.
+    pub fn estimate_fees(&self, message: &OwnedMessage) -> Result<TransactionFees, ExecutionError> {
+        let (tx, _) = self.emulate(message)?;
+        TransactionFees::from_transaction(&tx)
+    }
+
+    /// Executes the transaction for the message locally, nothing is sent.
+    ///
+    /// The held account is replaced with the resulting state if `advance` is set.
+    /// A deleted account is replaced with an empty one.
+    pub fn emulate_transaction(
+        &mut self,
+        message: &OwnedMessage,
+        advance: bool,
+    ) -> Result<EmulatedTransaction, ExecutionError> {
+        let (transaction, shard_account) = self.emulate(message)?;
+        let account = shard_account.load_account()?;
+
+        if advance {
+            match &account {
+                Some(account) => {
+                    self.account = account.clone();
+                    self.exists = true;
+                }
+                None => {
+                    let IntAddr::Std(address) = &self.account.address else {
+                        return Err(ExecutionError::InvalidAddressType);
+                    };
+                    let mut empty = empty_account(address);
+                    empty.last_trans_lt = transaction.end_lt;
+                    self.account = empty;
+                    self.exists = false;
+                }
+            }
+            // Deleted accounts still link the next transaction to this one
+            self.last_transaction_id = Some(LastTransactionId {
+                lt: shard_account.last_trans_lt,
+                hash: shard_account.last_trans_hash,
+            });
+        }
+
+        Ok(EmulatedTransaction {
+            transaction,
+            account,
+        })
+    }
+
+    fn emulate(
+        &self,
+        message: &OwnedMessage,
+    ) -> Result<(Transaction, ShardAccount), ExecutionError> {
+        let GenTimings { gen_utime, gen_lt } =
+            get_gen_timings(self.context.clock(), self.account.last_trans_lt);
+
+        let config = self.context.parsed_config(gen_utime)?;
+
+        let mut params = self.context.executor_params().clone();
+        params.block_unixtime = gen_utime;
+        params.block_lt = gen_lt;
+
+        // Both parts of the id must come from the same transaction,
+        // an account without one is treated as having no transactions
+        let (last_trans_lt, last_trans_hash) = match self.last_transaction_id {
+            Some(id) => (id.lt, id.hash),
+            None => (0, HashBytes::ZERO),
+        };
+        let shard_account = ShardAccount {
+            account: Lazy::new(&OptionalAccount(self.exists.then(|| self.account.clone())))?,
+            last_trans_hash,
+            last_trans_lt,
+        };
+
+        local_executor::emulate_ordinary_transaction(&shard_account, message, &params, &config)
+    }
+
     pub fn run_getter<M>(
         &self,
         method_id: &M,
@@ -118,13 +418,173 @@ impl BlockchainAccount {
     where
         M: VmGetterMethodId + ?Sized,
     {
-        self.run_getter_ext(method_id, args)
+        self.run_getter_ext(method_id, args, &self.account, &self.context.config())
+    }
+
+    /// Runs the getter with arguments and results converted from and to Rust types.
+    ///
+    /// ```ignore
+    /// let (wallet,): (StdAddr,) = account.run_getter_typed("get_wallet_address", (owner,))?;
+    /// ```
+    pub fn run_getter_typed<M, A, R>(&self, method_id: &M, args: A) -> Result<R, GetterError>
+    where
+        M: VmGetterMethodId + ?Sized,
+        A: StackArgs,
+        R: StackResult,
+    {
+        let args = args.into_stack().map_err(GetterError::Args)?;
+        let output =
+            self.run_getter_ext(method_id, &args, &self.account, &self.context.config())?;
+        if !output.success {
+            return Err(GetterError::ExitCode(output.exit_code));
+        }
+        R::from_stack(output.stack.into_iter().collect()).map_err(GetterError::Result)
+    }
+
+    /// Runs the getter and records executed instructions.
+    pub fn run_getter_traced<M>(
+        &self,
+        method_id: &M,
+        args: &[RcStackValue],
+    ) -> Result<(VmGetterOutput, ExecutionTrace), GetterError>
+    where
+        M: VmGetterMethodId + ?Sized,
+    {
+        let (output, trace) = ExecutionTrace::record(|| {
+            self.run_getter_ext(method_id, args, &self.account, &self.context.config())
+        });
+        Ok((output?, trace?))
+    }
+
+    /// Runs the getter against the account and config with overrides applied.
+    ///
+    /// Block time and random seed overrides replace the ones
+    /// the getter sees with `NOW` and `RANDSEED`.
+    pub fn run_getter_with_overrides<M>(
+        &self,
+        method_id: &M,
+        args: &[RcStackValue],
+        overrides: &StateOverrides,
+    ) -> anyhow::Result<VmGetterOutput>
+    where
+        M: VmGetterMethodId + ?Sized,
+    {
+        let account = overrides.apply_to_account(&self.account);
+        let config = overrides.apply_to_config(&self.context.config())?;
+        if overrides.block_utime.is_none() && overrides.rand_seed.is_none() {
+            return Ok(self.run_getter_ext(method_id, args, &account, &config)?);
+        }
+
+        let now = overrides
+            .block_utime
+            .unwrap_or_else(|| self.context.clock().now_sec_u64() as u32);
+        let rand_seed = overrides
+            .rand_seed
+            .unwrap_or(self.context.executor_params().rand_seed);
+        self.run_getter_at(method_id, args, &account, &config, now, &rand_seed)
+    }
+
+    /// Same as [`run_getter_typed`](Self::run_getter_typed) with overrides applied.
+    pub fn run_getter_typed_with_overrides<M, A, R>(
+        &self,
+        method_id: &M,
+        args: A,
+        overrides: &StateOverrides,
+    ) -> anyhow::Result<R>
+    where
+        M: VmGetterMethodId + ?Sized,
+        A: StackArgs,
+        R: StackResult,
+    {
+        let args = args.into_stack().map_err(GetterError::Args)?;
+        let output = self.run_getter_with_overrides(method_id, &args, overrides)?;
+        if !output.success {
+            return Err(GetterError::ExitCode(output.exit_code).into());
+        }
+        Ok(R::from_stack(output.stack.into_iter().collect()).map_err(GetterError::Result)?)
+    }
+
+    /// Same as [`run_getter_traced`](Self::run_getter_traced) with overrides applied.
+    pub fn run_getter_traced_with_overrides<M>(
+        &self,
+        method_id: &M,
+        args: &[RcStackValue],
+        overrides: &StateOverrides,
+    ) -> anyhow::Result<(VmGetterOutput, ExecutionTrace)>
+    where
+        M: VmGetterMethodId + ?Sized,
+    {
+        let (output, trace) =
+            ExecutionTrace::record(|| self.run_getter_with_overrides(method_id, args, overrides));
+        Ok((output?, trace.map_err(GetterError::Trace)?))
+    }
+
+    /// Runs the getter with the specified block time and random seed,
+    /// which [`VmCaller`] always takes from the current environment.
+    fn run_getter_at<M>(
+        &self,
+        method_id: &M,
+        args: &[RcStackValue],
+        account: &Account,
+        config: &BlockchainConfig,
+        now: u32,
+        rand_seed: &HashBytes,
+    ) -> anyhow::Result<VmGetterOutput>
+    where
+        M: VmGetterMethodId + ?Sized,
+    {
+        let AccountState::Active(StateInit {
+            code: Some(code),
+            data,
+            ..
+        }) = &account.state
+        else {
+            anyhow::bail!("account is not active");
+        };
+        let IntAddr::Std(address) = &account.address else {
+            return Err(ExecutionError::InvalidAddressType.into());
+        };
+
+        // Same seed as in transactions of the account
+        let smc_info = SmcInfoBase::new()
+            .with_now(now)
+            .with_block_lt(account.last_trans_lt)
+            .with_tx_lt(account.last_trans_lt)
+            .with_mixed_rand_seed(rand_seed, &address.address)
+            .with_account_balance(account.balance.clone())
+            .with_account_addr(account.address.clone())
+            .with_config(config.params.clone())
+            .require_ton_v4()
+            .with_code(code.clone());
+
+        let mut stack = args.to_vec();
+        stack.push(RcStackValue::new_dyn_value(BigInt::from(
+            method_id.as_getter_method_id(),
+        )));
+
+        let mut vm = VmState::builder()
+            .with_smc_info(smc_info)
+            .with_code(code.clone())
+            .with_data(data.clone().unwrap_or_default())
+            .with_stack(stack)
+            .with_libraries(&self.context.executor_params().libraries)
+            .with_gas(GasParams::getter())
+            .build();
+
+        let exit_code = !vm.run();
+        Ok(VmGetterOutput {
+            exit_code,
+            stack: vm.stack.items.clone(),
+            success: exit_code == 0 || exit_code == 1,
+        })
     }
 
     fn run_getter_ext<M>(
         &self,
         method_id: &M,
         args: &[RcStackValue],
+        account: &Account,
+        config: &BlockchainConfig,
     ) -> Result<VmGetterOutput, VmGetterError>
     where
         M: VmGetterMethodId + ?Sized,
@@ -137,10 +597,20 @@ impl BlockchainAccount {
         let caller = VmCaller {
             libraries: self.context.executor_params().libraries.clone(),
             behaviour_modifiers: Default::default(),
-            config: self.context.desc.config.params.clone(),
+            config: config.params.clone(),
         };
 
-        caller.call_getter(&self.account, method_id, stack_values)
+        caller.call_getter(account, method_id, stack_values)
+    }
+}
+
+fn empty_account(address: &StdAddr) -> Account {
+    Account {
+        address: IntAddr::Std(address.clone()),
+        storage_stat: Default::default(),
+        last_trans_lt: 0,
+        balance: Default::default(),
+        state: AccountState::Uninit,
     }
 }
 
@@ -186,17 +656,40 @@ impl BlockchainContextBuilder {
             anyhow::bail!("Failed to build BlockchainContext. Config is missing");
         };
 
+        self.build_with_config(config, None)
+    }
+
+    /// Builds the context with the latest config fetched from the transport.
+    /// The config set with [`with_config`](Self::with_config) is ignored.
+    pub async fn build_with_latest_config(self) -> anyhow::Result<BlockchainContext> {
+        let Some(transport) = &self.transport else {
+            anyhow::bail!("Failed to build BlockchainContext. Transport is missing");
+        };
+
+        let latest = transport.get_config().await?;
+        self.build_with_config(latest.config, Some(latest.seqno))
+    }
+
+    fn build_with_config(
+        self,
+        config: BlockchainConfig,
+        seqno: Option<u32>,
+    ) -> anyhow::Result<BlockchainContext> {
         let Some(transport) = self.transport else {
             anyhow::bail!("Failed to build BlockchainContext. Transport is missing");
         };
 
         Ok(BlockchainContext {
             desc: BlockchainDesc {
-                config: config.clone(),
                 executor_params: self.executor_params,
             },
+            config: Arc::new(RwLock::new(ConfigSnapshot {
+                seqno,
+                config: Arc::new(config),
+            })),
             transport,
             clock: self.clock,
+            parsed_config: Default::default(),
         })
     }
 }
@@ -209,6 +702,7 @@ impl Default for BlockchainContextBuilder {
 
 pub struct MessageBuilder {
     pub info: MsgInfo,
+    pub init: Option<StateInit>,
     pub body: OwnedCellSlice,
 }
 
@@ -221,6 +715,7 @@ impl MessageBuilder {
         });
         Self {
             info,
+            init: None,
             body: OwnedCellSlice::new_allow_exotic(Cell::empty_cell()),
         }
     }
@@ -232,10 +727,102 @@ impl MessageBuilder {
         });
         Self {
             info,
+            init: None,
             body: OwnedCellSlice::new_allow_exotic(Cell::empty_cell()),
         }
     }
 
+    pub fn new_external_out(src: IntAddr, dst: Option<ExtAddr>) -> Self {
+        let info = MsgInfo::ExtOut(ExtOutMsgInfo {
+            src,
+            dst,
+            ..Default::default()
+        });
+        Self {
+            info,
+            init: None,
+            body: OwnedCellSlice::new_allow_exotic(Cell::empty_cell()),
+        }
+    }
+
+    /// Sets the attached value with extra currencies.
+    /// Ignored for external messages.
+    pub fn with_value<T: Into<CurrencyCollection>>(mut self, value: T) -> Self {
+        if let MsgInfo::Int(info) = &mut self.info {
+            info.value = value.into();
+        }
+        self
+    }
+
+    /// Ignored for external messages.
+    pub fn with_bounce(mut self, bounce: bool) -> Self {
+        if let MsgInfo::Int(info) = &mut self.info {
+            info.bounce = bounce;
+        }
+        self
+    }
+
+    /// Ignored for external messages.
+    pub fn with_bounced(mut self, bounced: bool) -> Self {
+        if let MsgInfo::Int(info) = &mut self.info {
+            info.bounced = bounced;
+        }
+        self
+    }
+
+    /// Ignored for external messages.
+    pub fn with_ihr_disabled(mut self, ihr_disabled: bool) -> Self {
+        if let MsgInfo::Int(info) = &mut self.info {
+            info.ihr_disabled = ihr_disabled;
+        }
+        self
+    }
+
+    /// Ignored for external inbound messages.
+    pub fn with_created_lt(mut self, created_lt: u64) -> Self {
+        match &mut self.info {
+            MsgInfo::Int(info) => info.created_lt = created_lt,
+            MsgInfo::ExtOut(info) => info.created_lt = created_lt,
+            MsgInfo::ExtIn(_) => {}
+        }
+        self
+    }
+
+    /// Ignored for external inbound messages.
+    pub fn with_created_at(mut self, created_at: u32) -> Self {
+        match &mut self.info {
+            MsgInfo::Int(info) => info.created_at = created_at,
+            MsgInfo::ExtOut(info) => info.created_at = created_at,
+            MsgInfo::ExtIn(_) => {}
+        }
+        self
+    }
+
+    /// Ignored for external inbound messages.
+    pub fn with_src(mut self, src: IntAddr) -> Self {
+        match &mut self.info {
+            MsgInfo::Int(info) => info.src = src,
+            MsgInfo::ExtOut(info) => info.src = src,
+            MsgInfo::ExtIn(_) => {}
+        }
+        self
+    }
+
+    /// Sets the destination of an external outbound message.
+    /// Ignored for other messages.
+    pub fn with_ext_dst(mut self, dst: Option<ExtAddr>) -> Self {
+        if let MsgInfo::ExtOut(info) = &mut self.info {
+            info.dst = dst;
+        }
+        self
+    }
+
+    /// Attaches the state init to deploy the destination account.
+    pub fn with_state_init(mut self, init: StateInit) -> Self {
+        self.init = Some(init);
+        self
+    }
+
     pub fn with_body<T: IntoMessageBody>(mut self, body: T) -> anyhow::Result<Self> {
         self.body = body.into_message_body()?;
         Ok(self)
@@ -244,7 +831,7 @@ impl MessageBuilder {
     pub fn build(&self) -> OwnedMessage {
         OwnedMessage {
             info: self.info.clone(),
-            init: None,
+            init: self.init.clone(),
             body: self.body.clone().into(),
             layout: None,
         }
@@ -254,6 +841,47 @@ impl MessageBuilder {
         let cell = CellBuilder::build_from(self.build())?;
         Ok(cell)
     }
+
+    /// Builds the message and checks it against the size limits from the config.
+    pub fn build_checked(&self, config: &BlockchainConfig) -> anyhow::Result<OwnedMessage> {
+        let limits = config.get_size_limits()?;
+        let message = self.build();
+        let cell = CellBuilder::build_from(&message)?;
+
+        let Some(stats) = cell.compute_unique_stats(limits.max_msg_cells as usize + 1) else {
+            anyhow::bail!("message has too many cells");
+        };
+        anyhow::ensure!(
+            stats.cell_count <= limits.max_msg_cells as u64,
+            "message has too many cells: {} > {}",
+            stats.cell_count,
+            limits.max_msg_cells
+        );
+        anyhow::ensure!(
+            stats.bit_count <= limits.max_msg_bits as u64,
+            "message has too many bits: {} > {}",
+            stats.bit_count,
+            limits.max_msg_bits
+        );
+
+        if let MsgInfo::ExtIn(_) = &message.info {
+            anyhow::ensure!(
+                cell.repr_depth() <= limits.max_ext_msg_depth,
+                "external message is too deep: {} > {}",
+                cell.repr_depth(),
+                limits.max_ext_msg_depth
+            );
+
+            let size = Boc::encode(&cell).len();
+            anyhow::ensure!(
+                size <= limits.max_ext_msg_size as usize,
+                "external message is too big: {size} > {}",
+                limits.max_ext_msg_size
+            );
+        }
+
+        Ok(message)
+    }
 }
 
 pub trait IntoMessageBody {
@@ -267,6 +895,14 @@ impl IntoMessageBody for CellBuilder {
     }
 }
 
+/// Internal call of the ABI function with the specified arguments.
+impl IntoMessageBody for (&Function, &[NamedAbiValue]) {
+    fn into_message_body(self) -> anyhow::Result<OwnedCellSlice> {
+        let (function, args) = self;
+        function.encode_internal_input(args)?.into_message_body()
+    }
+}
+
 impl IntoMessageBody for Cell {
     fn into_message_body(self) -> anyhow::Result<OwnedCellSlice> {
         Ok(OwnedCellSlice::new_allow_exotic(self))
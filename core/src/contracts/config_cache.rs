@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use tycho_executor::ParsedConfig;
+use tycho_types::cell::HashBytes;
+use tycho_types::models::BlockchainConfig;
+
+use crate::error::ExecutionError;
+
+/// Parsed config shared between clones.
+///
+/// The config is parsed again only if it has changed or the block time
+/// has crossed the start of another storage prices period.
+#[derive(Default, Clone)]
+pub struct ParsedConfigCache {
+    inner: Arc<Mutex<Option<CachedConfig>>>,
+}
+
+struct CachedConfig {
+    address: HashBytes,
+    params_hash: Option<HashBytes>,
+    valid_since: u32,
+    valid_until: u32,
+    parsed: Arc<ParsedConfig>,
+}
+
+impl CachedConfig {
+    fn is_valid(&self, config: &BlockchainConfig, utime: u32) -> bool {
+        self.address == config.address
+            && self.params_hash == params_hash(config)
+            && (self.valid_since..self.valid_until).contains(&utime)
+    }
+}
+
+impl ParsedConfigCache {
+    pub fn get(
+        &self,
+        config: &BlockchainConfig,
+        utime: u32,
+    ) -> Result<Arc<ParsedConfig>, ExecutionError> {
+        if let Some(cached) = &*self.inner.lock() {
+            if cached.is_valid(config, utime) {
+                return Ok(cached.parsed.clone());
+            }
+        }
+
+        // Parsing is slow, so concurrent callers may parse the same config
+        // instead of waiting for each other
+        let parsed = Arc::new(ParsedConfig::parse(config.clone(), utime)?);
+        let (valid_since, valid_until) = time_bounds(config, utime);
+        *self.inner.lock() = Some(CachedConfig {
+            address: config.address,
+            params_hash: params_hash(config),
+            valid_since,
+            valid_until,
+            parsed: parsed.clone(),
+        });
+        Ok(parsed)
+    }
+
+    pub fn clear(&self) {
+        *self.inner.lock() = None;
+    }
+}
+
+fn params_hash(config: &BlockchainConfig) -> Option<HashBytes> {
+    config
+        .params
+        .as_dict()
+        .root()
+        .as_ref()
+        .map(|root| *root.repr_hash())
+}
+
+/// Returns the period of storage prices which contains `utime`.
+/// Falls back to the exact time if prices can't be read.
+fn time_bounds(config: &BlockchainConfig, utime: u32) -> (u32, u32) {
+    let Ok(prices) = config.get_storage_prices() else {
+        return (utime, utime.saturating_add(1));
+    };
+
+    let mut since = 0;
+    let mut until = u32::MAX;
+    for item in prices.values() {
+        let Ok(item) = item else {
+            return (utime, utime.saturating_add(1));
+        };
+        if item.utime_since <= utime {
+            since = since.max(item.utime_since);
+        } else {
+            until = until.min(item.utime_since);
+        }
+    }
+    (since, until)
+}
@@ -0,0 +1,307 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::Context;
+use num_bigint::{BigInt, BigUint};
+use serde_json::Value;
+use tycho_types::abi::{AbiType, AbiValue, Contract, Event, Function, NamedAbiType, NamedAbiValue};
+use tycho_types::boc::Boc;
+use tycho_types::models::{IntAddr, StdAddr, StdAddrFormat};
+use tycho_types::num::Tokens;
+
+use super::blockchain_context::BlockchainAccount;
+use super::function_ext::{ExecutionOutput, RunLocalOptions};
+use super::utils::answer_id;
+use crate::error::{AbiErrors, ExecutionError};
+
+/// Contract interface loaded at runtime, bound to an account.
+pub struct ContractHandle {
+    contract: Arc<Contract>,
+    errors: AbiErrors,
+    account: BlockchainAccount,
+}
+
+impl ContractHandle {
+    pub fn new(contract: Arc<Contract>, account: BlockchainAccount) -> Self {
+        Self {
+            contract,
+            errors: Default::default(),
+            account,
+        }
+    }
+
+    /// Parses the ABI JSON together with its optional `errors` list.
+    pub fn from_abi_json(abi: &str, account: BlockchainAccount) -> anyhow::Result<Self> {
+        let contract = serde_json::from_str::<Contract>(abi).context("invalid ABI")?;
+        let errors = AbiErrors::from_abi_json(abi)?;
+        Ok(Self::new(Arc::new(contract), account).with_errors(errors))
+    }
+
+    /// Sets names of custom exit codes.
+    pub fn with_errors(mut self, errors: AbiErrors) -> Self {
+        self.errors = errors;
+        self
+    }
+
+    pub fn contract(&self) -> &Contract {
+        &self.contract
+    }
+
+    pub fn errors(&self) -> &AbiErrors {
+        &self.errors
+    }
+
+    pub fn account(&self) -> &BlockchainAccount {
+        &self.account
+    }
+
+    pub fn account_mut(&mut self) -> &mut BlockchainAccount {
+        &mut self.account
+    }
+
+    pub fn functions(&self) -> impl Iterator<Item = &Function> {
+        self.contract.functions.values()
+    }
+
+    pub fn events(&self) -> impl Iterator<Item = &Event> {
+        self.contract.events.values()
+    }
+
+    /// Storage fields described by the ABI.
+    pub fn fields(&self) -> &[NamedAbiType] {
+        &self.contract.fields
+    }
+
+    /// Decodes the storage fields of the current account state.
+    pub fn storage(&self) -> Result<Vec<NamedAbiValue>, ExecutionError> {
+        self.account.decode_storage(&self.contract)
+    }
+
+    pub fn function(&self, name: &str) -> Result<&Function, ExecutionError> {
+        self.contract
+            .functions
+            .get(name)
+            .ok_or_else(|| anyhow::anyhow!("function `{name}` not found").into())
+    }
+
+    /// Runs the function locally.
+    ///
+    /// Responsible functions are called with an internal message, the answer id
+    /// is added automatically if it is omitted from `args`.
+    pub fn call(
+        &self,
+        name: &str,
+        args: &[NamedAbiValue],
+    ) -> Result<ExecutionOutput, ExecutionError> {
+        self.call_ext(name, args, &Default::default())
+    }
+
+    pub fn call_ext(
+        &self,
+        name: &str,
+        args: &[NamedAbiValue],
+        options: &RunLocalOptions<'_>,
+    ) -> Result<ExecutionOutput, ExecutionError> {
+        let function = self.function(name)?;
+
+        if !is_responsible(function) {
+            return self.account.run_local_ext(function, args, options);
+        }
+
+        if args.len() + 1 == function.inputs.len() {
+            let mut full_args = Vec::with_capacity(function.inputs.len());
+            let mut id = answer_id();
+            id.name = function.inputs[0].name.clone();
+            full_args.push(id);
+            full_args.extend_from_slice(args);
+            self.account
+                .run_local_responsible_ext(function, &full_args, options)
+        } else {
+            self.account
+                .run_local_responsible_ext(function, args, options)
+        }
+    }
+
+    /// Runs the function with arguments from a JSON object keyed by input names.
+    pub fn call_json(&self, name: &str, args: &Value) -> Result<ExecutionOutput, ExecutionError> {
+        let function = self.function(name)?;
+
+        let mut inputs = function.inputs.as_ref();
+        if is_responsible(function) && !has_field(args, &inputs[0].name) {
+            inputs = &inputs[1..];
+        }
+        let args = parse_named_values(inputs, args)?;
+
+        self.call(name, &args)
+    }
+}
+
+/// Responsible functions take the answer id as the first input.
+fn is_responsible(function: &Function) -> bool {
+    matches!(
+        function.inputs.first(),
+        Some(NamedAbiType { name, ty: AbiType::Uint(32) })
+            if name.as_ref() == "answerId" || name.as_ref() == "_answer_id"
+    )
+}
+
+fn has_field(value: &Value, name: &str) -> bool {
+    value.as_object().is_some_and(|map| map.contains_key(name))
+}
+
+/// Converts a JSON object into ABI values of the specified types.
+pub fn parse_named_values(
+    types: &[NamedAbiType],
+    value: &Value,
+) -> anyhow::Result<Vec<NamedAbiValue>> {
+    let object = match value {
+        Value::Object(object) => object,
+        Value::Null if types.is_empty() => return Ok(Vec::new()),
+        _ => anyhow::bail!("expected an object with arguments"),
+    };
+
+    types
+        .iter()
+        .map(|item| {
+            let value = object
+                .get(item.name.as_ref())
+                .with_context(|| format!("missing argument `{}`", item.name))?;
+            let value = parse_value(&item.ty, value)
+                .with_context(|| format!("invalid argument `{}`", item.name))?;
+            Ok(NamedAbiValue {
+                name: item.name.clone(),
+                value,
+            })
+        })
+        .collect()
+}
+
+fn parse_value(ty: &AbiType, value: &Value) -> anyhow::Result<AbiValue> {
+    Ok(match ty {
+        AbiType::Uint(bits) => AbiValue::Uint(*bits, parse_biguint(value)?),
+        AbiType::Int(bits) => AbiValue::Int(*bits, parse_bigint(value)?),
+        AbiType::VarUint(size) => AbiValue::VarUint(*size, parse_biguint(value)?),
+        AbiType::VarInt(size) => AbiValue::VarInt(*size, parse_bigint(value)?),
+        AbiType::Bool => AbiValue::Bool(value.as_bool().context("expected a boolean")?),
+        AbiType::Token => {
+            let tokens = parse_biguint(value)?
+                .try_into()
+                .map(Tokens::new)
+                .ok()
+                .filter(Tokens::is_valid)
+                .context("tokens value is too big")?;
+            AbiValue::Token(tokens)
+        }
+        AbiType::Cell => AbiValue::Cell(Boc::decode_base64(as_str(value)?)?),
+        AbiType::Address => {
+            let (address, _) = StdAddr::from_str_ext(as_str(value)?, StdAddrFormat::any())?;
+            AbiValue::Address(Box::new(IntAddr::Std(address).into()))
+        }
+        AbiType::Bytes => AbiValue::Bytes(hex::decode(as_str(value)?)?.into()),
+        AbiType::FixedBytes(len) => {
+            let bytes = hex::decode(as_str(value)?)?;
+            anyhow::ensure!(bytes.len() == *len, "expected {len} bytes");
+            AbiValue::FixedBytes(bytes.into())
+        }
+        AbiType::String => AbiValue::String(as_str(value)?.to_owned()),
+        AbiType::Tuple(types) => AbiValue::Tuple(parse_named_values(types, value)?),
+        AbiType::Array(ty) => AbiValue::Array(ty.clone(), parse_items(ty, value)?),
+        AbiType::FixedArray(ty, len) => {
+            let items = parse_items(ty, value)?;
+            anyhow::ensure!(items.len() == *len, "expected {len} items");
+            AbiValue::FixedArray(ty.clone(), items)
+        }
+        AbiType::Optional(ty) => {
+            let inner = match value {
+                Value::Null => None,
+                value => Some(Box::new(parse_value(ty, value)?)),
+            };
+            AbiValue::Optional(ty.clone(), inner)
+        }
+        AbiType::Ref(ty) => AbiValue::Ref(Box::new(parse_value(ty, value)?)),
+        ty => anyhow::bail!("unsupported argument type {ty}"),
+    })
+}
+
+fn parse_items(ty: &AbiType, value: &Value) -> anyhow::Result<Vec<AbiValue>> {
+    value
+        .as_array()
+        .context("expected an array")?
+        .iter()
+        .map(|item| parse_value(ty, item))
+        .collect()
+}
+
+fn as_str(value: &Value) -> anyhow::Result<&str> {
+    value.as_str().context("expected a string")
+}
+
+/// Integers are accepted as JSON numbers or as decimal and `0x` hex strings.
+fn parse_bigint(value: &Value) -> anyhow::Result<BigInt> {
+    match value {
+        Value::Number(number) => Ok(BigInt::from_str(&number.to_string())?),
+        Value::String(string) => {
+            let (negative, string) = match string.strip_prefix('-') {
+                Some(string) => (true, string),
+                None => (false, string.as_str()),
+            };
+            let int = match string.strip_prefix("0x") {
+                Some(hex) => BigInt::parse_bytes(hex.as_bytes(), 16).context("invalid hex")?,
+                None => BigInt::from_str(string)?,
+            };
+            Ok(if negative { -int } else { int })
+        }
+        _ => anyhow::bail!("expected an integer"),
+    }
+}
+
+fn parse_biguint(value: &Value) -> anyhow::Result<BigUint> {
+    parse_bigint(value)?
+        .to_biguint()
+        .context("expected a non-negative integer")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_json_args() -> anyhow::Result<()> {
+        let types = [
+            AbiType::Uint(32).named("call_id"),
+            AbiType::Int(64).named("delta"),
+            AbiType::Bool.named("flag"),
+            AbiType::Address.named("owner"),
+            AbiType::Array(Arc::new(AbiType::Uint(8))).named("items"),
+        ];
+
+        let args = json!({
+            "call_id": 7,
+            "delta": "-0x10",
+            "flag": true,
+            "owner": "0:0000000000000000000000000000000000000000000000000000000000000000",
+            "items": [1, "2"],
+        });
+
+        let values = parse_named_values(&types, &args)?;
+        assert_eq!(values[0].value, AbiValue::Uint(32, BigUint::from(7u32)));
+        assert_eq!(values[1].value, AbiValue::Int(64, BigInt::from(-16)));
+        assert_eq!(values[2].value, AbiValue::Bool(true));
+        assert_eq!(
+            values[4].value,
+            AbiValue::Array(
+                Arc::new(AbiType::Uint(8)),
+                vec![
+                    AbiValue::Uint(8, BigUint::from(1u32)),
+                    AbiValue::Uint(8, BigUint::from(2u32)),
+                ]
+            )
+        );
+
+        let err = parse_named_values(&types[..1], &json!({})).unwrap_err();
+        assert!(err.to_string().contains("call_id"));
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+use ed25519_dalek::VerifyingKey;
+use tycho_types::abi::{Contract, NamedAbiValue};
+use tycho_types::boc::Boc;
+use tycho_types::cell::{Cell, CellBuilder};
+use tycho_types::models::{IntAddr, OwnedMessage, StateInit, StdAddr};
+use tycho_types::num::Tokens;
+
+use super::blockchain_context::{IntoMessageBody, MessageBuilder};
+
+/// Builder of the initial state of a contract.
+pub struct StateInitBuilder {
+    state_init: StateInit,
+}
+
+impl StateInitBuilder {
+    pub fn new(state_init: StateInit) -> Self {
+        Self { state_init }
+    }
+
+    /// Creates a state with the specified code and without data.
+    pub fn from_code(code: Cell) -> Self {
+        Self::new(StateInit {
+            code: Some(code),
+            ..Default::default()
+        })
+    }
+
+    /// Loads the state from a TVC, which is a BOC with the `StateInit`.
+    pub fn from_tvc(tvc: &[u8]) -> anyhow::Result<Self> {
+        let state_init = Boc::decode(tvc)?.parse::<StateInit>()?;
+        Ok(Self::new(state_init))
+    }
+
+    pub fn from_tvc_base64(tvc: &str) -> anyhow::Result<Self> {
+        let state_init = Boc::decode_base64(tvc)?.parse::<StateInit>()?;
+        Ok(Self::new(state_init))
+    }
+
+    /// Sets the initial data fields and the public key described by the ABI.
+    ///
+    /// Fields which are not specified keep their values from the loaded data.
+    pub fn with_init_data(
+        mut self,
+        contract: &Contract,
+        public_key: Option<&VerifyingKey>,
+        fields: &[NamedAbiValue],
+    ) -> anyhow::Result<Self> {
+        let data = match (&self.state_init.data, public_key) {
+            (Some(data), public_key) => contract.update_init_data(public_key, fields, data)?,
+            (None, Some(public_key)) => contract.encode_init_data(public_key, fields)?,
+            (None, None) => anyhow::bail!("public key is required to encode the initial data"),
+        };
+        self.state_init.data = Some(data);
+        Ok(self)
+    }
+
+    pub fn build(self, workchain: i8) -> anyhow::Result<Deployment> {
+        let hash = *CellBuilder::build_from(&self.state_init)?.repr_hash();
+        Ok(Deployment {
+            address: StdAddr::new(workchain, hash),
+            state_init: self.state_init,
+        })
+    }
+}
+
+/// Initial state of a contract with its future address.
+#[derive(Debug, Clone)]
+pub struct Deployment {
+    pub address: StdAddr,
+    pub state_init: StateInit,
+}
+
+impl Deployment {
+    /// Builds an external message which deploys the contract,
+    /// e.g. with a signed constructor call as the body.
+    pub fn external_message<T: IntoMessageBody>(&self, body: T) -> anyhow::Result<OwnedMessage> {
+        let message = MessageBuilder::new_external_in(IntAddr::Std(self.address.clone()))
+            .with_state_init(self.state_init.clone())
+            .with_body(body)?
+            .build();
+        Ok(message)
+    }
+
+    /// Builds a non-bounceable internal message which deploys the contract.
+    pub fn internal_message<T: IntoMessageBody>(
+        &self,
+        src: IntAddr,
+        value: Tokens,
+        body: T,
+    ) -> anyhow::Result<OwnedMessage> {
+        let message = MessageBuilder::new_internal_in(src, IntAddr::Std(self.address.clone()))
+            .with_value(value)
+            .with_bounce(false)
+            .with_state_init(self.state_init.clone())
+            .with_body(body)?
+            .build();
+        Ok(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Context;
+    use ed25519_dalek::SigningKey;
+    use futures_util::FutureExt;
+    use num_bigint::BigUint;
+    use tycho_types::abi::AbiValue;
+    use tycho_types::cell::{CellFamily, HashBytes};
+    use tycho_types::models::AccountState;
+
+    use super::*;
+    use crate::contracts::tests::{code_cell, test_context};
+    use crate::error::ExecutionError;
+
+    #[test]
+    fn deployment_address_is_state_init_hash() -> anyhow::Result<()> {
+        let code = CellBuilder::build_from(0xdeadbeefu32)?;
+        let deployment = StateInitBuilder::from_code(code.clone()).build(0)?;
+
+        let expected = CellBuilder::build_from(&deployment.state_init)?;
+        assert_eq!(deployment.address.address, *expected.repr_hash());
+        assert_eq!(deployment.address.workchain, 0);
+
+        let message = deployment.external_message(Cell::empty_cell())?;
+        let init = message.init.expect("state init attached");
+        assert_eq!(init.code.as_ref(), Some(&code));
+        Ok(())
+    }
+
+    #[test]
+    fn deploy_with_init_data() -> anyhow::Result<()> {
+        let (context, _) = test_context()?;
+
+        let contract = serde_json::from_str::<Contract>(include_str!("../test/abi.json"))?;
+        let public_key = SigningKey::from_bytes(&[7; 32]).verifying_key();
+        let fields = [AbiValue::Uint(32, BigUint::from(5u32)).named("deploy_nonce")];
+        let data = contract.encode_init_data(&public_key, &fields)?;
+
+        // ACCEPT DROP
+        let code = code_cell(&[0xf8, 0x00, 0x30], &[])?;
+        let deployment = StateInitBuilder::from_code(code.clone())
+            .with_init_data(&contract, Some(&public_key), &fields)?
+            .build(0)?;
+
+        let fork = context.fork();
+        let message = deployment.internal_message(
+            IntAddr::Std(StdAddr::new(0, HashBytes([0x22; 32]))),
+            Tokens::new(1_000_000_000),
+            Cell::empty_cell(),
+        )?;
+        let execution = fork
+            .send_message(&message)
+            .now_or_never()
+            .context("send is not ready")??;
+        ExecutionError::check_transaction(&execution.transactions[0], None)?;
+
+        let account = fork
+            .get_account(&deployment.address)
+            .now_or_never()
+            .context("get is not ready")??
+            .context("account is not deployed")?;
+        let AccountState::Active(state) = &account.state else {
+            anyhow::bail!("account is not active");
+        };
+        assert_eq!(state.code.as_ref(), Some(&code));
+        assert_eq!(
+            state.data.as_ref().map(|data| *data.repr_hash()),
+            Some(*data.repr_hash())
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use tycho_types::abi::{Contract, NamedAbiValue};
+use tycho_types::cell::CellSlice;
+use tycho_types::models::{
+    MsgInfo, OwnedMessage, OwnedRelaxedMessage, RelaxedMsgInfo, Transaction,
+};
+use tycho_vm::OwnedCellSlice;
+
+/// Event emitted by a contract in an external outbound message.
+#[derive(Debug, Clone)]
+pub struct DecodedEvent {
+    pub name: Arc<str>,
+    pub values: Vec<NamedAbiValue>,
+}
+
+pub trait ContractExt {
+    /// Decodes the body of an external outbound message.
+    ///
+    /// Returns `None` if the body doesn't start with a known event id.
+    fn decode_event(&self, body: CellSlice<'_>) -> anyhow::Result<Option<DecodedEvent>>;
+
+    /// Decodes all events from the messages produced by a local execution.
+    fn decode_events<'a, I>(&self, messages: I) -> anyhow::Result<Vec<DecodedEvent>>
+    where
+        I: IntoIterator<Item = &'a OwnedRelaxedMessage>;
+
+    /// Decodes all events from the outbound messages of the transaction.
+    fn decode_transaction_events(&self, tx: &Transaction) -> anyhow::Result<Vec<DecodedEvent>>;
+}
+
+impl ContractExt for Contract {
+    fn decode_event(&self, body: CellSlice<'_>) -> anyhow::Result<Option<DecodedEvent>> {
+        let Ok(id) = body.get_u32(0) else {
+            return Ok(None);
+        };
+        let Some(event) = self.find_event_by_id(id) else {
+            return Ok(None);
+        };
+
+        let values = event.decode_input(body)?;
+        Ok(Some(DecodedEvent {
+            name: event.name.clone(),
+            values,
+        }))
+    }
+
+    fn decode_events<'a, I>(&self, messages: I) -> anyhow::Result<Vec<DecodedEvent>>
+    where
+        I: IntoIterator<Item = &'a OwnedRelaxedMessage>,
+    {
+        let mut events = Vec::new();
+        for message in messages {
+            if !matches!(message.info, RelaxedMsgInfo::ExtOut(_)) {
+                continue;
+            }
+            let body = OwnedCellSlice::from(message.body.clone());
+            if let Some(event) = self.decode_event(body.apply())? {
+                events.push(event);
+            }
+        }
+        Ok(events)
+    }
+
+    fn decode_transaction_events(&self, tx: &Transaction) -> anyhow::Result<Vec<DecodedEvent>> {
+        let mut events = Vec::new();
+        for item in tx.out_msgs.values() {
+            let message = item?.parse::<OwnedMessage>()?;
+            if !matches!(message.info, MsgInfo::ExtOut(_)) {
+                continue;
+            }
+            let body = OwnedCellSlice::from(message.body);
+            if let Some(event) = self.decode_event(body.apply())? {
+                events.push(event);
+            }
+        }
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use tycho_types::abi::AbiValue;
+    use tycho_types::cell::{Cell, CellBuilder, CellFamily};
+
+    use super::*;
+
+    fn load_contract() -> anyhow::Result<Contract> {
+        Ok(serde_json::from_str(include_str!("../test/abi.json"))?)
+    }
+
+    #[test]
+    fn decode_events_from_ext_out() -> anyhow::Result<()> {
+        let contract = load_contract()?;
+        let event = contract
+            .events
+            .get("WhitelistPriceUpdate")
+            .expect("event exists");
+
+        let mut builder = CellBuilder::new();
+        builder.store_u32(event.id)?;
+        builder.store_u32(7)?;
+        builder.store_u128(1000)?;
+        let body = builder.build()?;
+
+        let make_message = |info| OwnedRelaxedMessage {
+            info,
+            init: None,
+            body: OwnedCellSlice::new_allow_exotic(body.clone()).into(),
+            layout: None,
+        };
+        let messages = [
+            make_message(RelaxedMsgInfo::ExtOut(Default::default())),
+            make_message(RelaxedMsgInfo::Int(Default::default())),
+        ];
+
+        let events = contract.decode_events(&messages)?;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].name.as_ref(), "WhitelistPriceUpdate");
+        assert_eq!(
+            events[0].values[1].value,
+            AbiValue::Uint(128, BigUint::from(1000u32))
+        );
+
+        // Unknown event ids are skipped.
+        let unknown = CellBuilder::build_from(0u32)?;
+        assert!(contract.decode_event(unknown.as_slice()?)?.is_none());
+        assert!(contract
+            .decode_event(Cell::empty_cell().as_slice()?)?
+            .is_none());
+        Ok(())
+    }
+}
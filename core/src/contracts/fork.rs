@@ -0,0 +1,488 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use anyhow::Context;
+use tycho_types::cell::{HashBytes, Lazy};
+use tycho_types::models::{
+    Account, MsgType, OptionalAccount, OwnedMessage, ShardAccount, StdAddr, Transaction,
+};
+
+use super::blockchain_context::BlockchainContext;
+use super::local_executor;
+use super::utils::get_gen_timings;
+use crate::error::ExecutionError;
+use crate::models::{ContractState, LastTransactionId, LatestBlockchainConfig};
+use crate::transport::Transport;
+
+/// Local copy of the chain on top of the context transport.
+///
+/// Accounts are fetched on first use and cached. Messages are executed
+/// locally and produced internal messages are delivered through the forked
+/// state, nothing is broadcast.
+pub struct ForkedChain {
+    context: BlockchainContext,
+    transport: Arc<ForkedTransport>,
+}
+
+/// Transactions produced by a message sent to the forked chain.
+#[derive(Debug, Clone, Default)]
+pub struct ForkedExecution {
+    /// Transactions in the order of execution.
+    pub transactions: Vec<Transaction>,
+    /// Internal messages left undelivered after the transactions limit was reached.
+    pub pending: Vec<OwnedMessage>,
+}
+
+impl ForkedChain {
+    const DEFAULT_MAX_TRANSACTIONS: usize = 1000;
+
+    pub fn new(context: BlockchainContext) -> Self {
+        let transport = Arc::new(ForkedTransport {
+            origin: context.clone(),
+            state: Mutex::new(ForkState {
+                max_transactions: Self::DEFAULT_MAX_TRANSACTIONS,
+                ..Default::default()
+            }),
+        });
+        Self {
+            context: context.with_transport(transport.clone()),
+            transport,
+        }
+    }
+
+    /// Limits the number of transactions executed for one sent message.
+    pub fn with_max_transactions(self, max_transactions: usize) -> Self {
+        self.transport.state.lock().unwrap().max_transactions = max_transactions;
+        self
+    }
+
+    /// Context over the forked state.
+    ///
+    /// Accounts fetched through it see the forked state and messages sent
+    /// through it are executed in the fork.
+    pub fn context(&self) -> &BlockchainContext {
+        &self.context
+    }
+
+    /// Returns the forked account state, fetching it on first use.
+    pub async fn get_account(&self, address: &StdAddr) -> anyhow::Result<Option<Account>> {
+        let shard_account = self.transport.shard_account(address).await?;
+        Ok(shard_account.load_account()?)
+    }
+
+    /// Replaces the account state in the fork, e.g. to deploy a contract
+    /// or to top up a balance.
+    pub fn set_account(&self, account: Account) -> anyhow::Result<()> {
+        let Some(address) = account.address.as_std().cloned() else {
+            anyhow::bail!("unsupported address type");
+        };
+
+        let mut state = self.transport.state.lock().unwrap();
+        state.last_lt = state.last_lt.max(account.last_trans_lt);
+
+        let last_trans_hash = state
+            .accounts
+            .get(&address)
+            .map(|item| item.last_trans_hash)
+            .unwrap_or_default();
+        let shard_account = ShardAccount {
+            last_trans_lt: account.last_trans_lt,
+            account: Lazy::new(&OptionalAccount(Some(account)))?,
+            last_trans_hash,
+        };
+        state.accounts.insert(address, shard_account);
+        Ok(())
+    }
+
+    /// Drops the cached state, it will be fetched again on next use.
+    pub fn reset_account(&self, address: &StdAddr) {
+        self.transport
+            .state
+            .lock()
+            .unwrap()
+            .accounts
+            .remove(address);
+    }
+
+    /// Executes the message and all internal messages produced by it.
+    ///
+    /// The forked state is updated only if the whole cascade succeeds,
+    /// on error it is left as it was before the call.
+    pub async fn send_message(
+        &self,
+        message: &OwnedMessage,
+    ) -> Result<ForkedExecution, ExecutionError> {
+        self.transport.execute(message).await
+    }
+}
+
+/// Transport which serves the forked state and executes sent messages
+/// in the fork. Everything else is requested from the original transport.
+struct ForkedTransport {
+    origin: BlockchainContext,
+    state: Mutex<ForkState>,
+}
+
+#[derive(Default)]
+struct ForkState {
+    accounts: HashMap<StdAddr, ShardAccount>,
+    transactions: HashMap<HashBytes, Transaction>,
+    /// Hashes of transactions by the hashes of their inbound messages.
+    dst_transactions: HashMap<HashBytes, HashBytes>,
+    last_lt: u64,
+    max_transactions: usize,
+}
+
+impl ForkedTransport {
+    async fn execute(&self, message: &OwnedMessage) -> Result<ForkedExecution, ExecutionError> {
+        let utime = self.origin.clock().now_sec_u64() as u32;
+        let config = self.origin.parsed_config(utime)?;
+
+        let mut params = self.origin.executor_params().clone();
+        params.block_unixtime = utime;
+
+        let (mut last_lt, max_transactions) = {
+            let state = self.state.lock().unwrap();
+            (state.last_lt, state.max_transactions)
+        };
+
+        // Changes are staged and applied only after the whole cascade succeeds
+        let mut staged = HashMap::<StdAddr, ShardAccount>::new();
+        let mut transactions = Vec::new();
+
+        let mut execution = ForkedExecution::default();
+        let mut queue = VecDeque::from([message.clone()]);
+
+        while let Some(message) = queue.pop_front() {
+            if execution.transactions.len() >= max_transactions {
+                queue.push_front(message);
+                break;
+            }
+
+            let address = local_executor::message_destination(&message)?.clone();
+            let shard_account = match staged.get(&address) {
+                Some(shard_account) => shard_account.clone(),
+                None => {
+                    let shard_account = self.shard_account(&address).await?;
+                    last_lt = last_lt.max(shard_account.last_trans_lt);
+                    shard_account
+                }
+            };
+
+            params.block_lt = last_lt + 1;
+            let (tx, shard_account) = local_executor::emulate_ordinary_transaction(
+                &shard_account,
+                &message,
+                &params,
+                &config,
+            )?;
+
+            for item in tx.out_msgs.values() {
+                let out_msg = item?.parse::<OwnedMessage>()?;
+                if matches!(out_msg.ty(), MsgType::Int) {
+                    queue.push_back(out_msg);
+                }
+            }
+
+            last_lt = last_lt.max(tx.end_lt);
+            transactions.push((shard_account.last_trans_hash, tx.clone()));
+            staged.insert(address, shard_account);
+            execution.transactions.push(tx);
+        }
+
+        let mut state = self.state.lock().unwrap();
+        state.accounts.extend(staged);
+        state.last_lt = state.last_lt.max(last_lt);
+        for (hash, tx) in transactions {
+            if let Some(in_msg) = &tx.in_msg {
+                state.dst_transactions.insert(*in_msg.repr_hash(), hash);
+            }
+            state.transactions.insert(hash, tx);
+        }
+
+        execution.pending = queue.into();
+        Ok(execution)
+    }
+
+    async fn shard_account(&self, address: &StdAddr) -> anyhow::Result<ShardAccount> {
+        let cached = self.state.lock().unwrap().accounts.get(address).cloned();
+        if let Some(shard_account) = cached {
+            return Ok(shard_account);
+        }
+
+        let state = self
+            .origin
+            .transport()
+            .get_contract_state(address, None)
+            .await?;
+
+        let shard_account = match state {
+            ContractState::Exists {
+                account,
+                last_transaction_id,
+                ..
+            } => ShardAccount {
+                account: Lazy::new(&OptionalAccount(Some(*account)))?,
+                last_trans_hash: last_transaction_id.hash,
+                last_trans_lt: last_transaction_id.lt,
+            },
+            ContractState::NotExists { .. } => ShardAccount {
+                account: Lazy::new(&OptionalAccount(None))?,
+                last_trans_hash: Default::default(),
+                last_trans_lt: 0,
+            },
+            ContractState::Unchanged { .. } => {
+                anyhow::bail!("unexpected unchanged state for a new account")
+            }
+        };
+
+        // The state might have been cached or changed while it was fetched
+        let mut state = self.state.lock().unwrap();
+        state.last_lt = state.last_lt.max(shard_account.last_trans_lt);
+        Ok(state
+            .accounts
+            .entry(address.clone())
+            .or_insert(shard_account)
+            .clone())
+    }
+}
+
+#[async_trait::async_trait]
+impl Transport for ForkedTransport {
+    async fn send_message(&self, message: &OwnedMessage) -> anyhow::Result<()> {
+        self.execute(message).await?;
+        Ok(())
+    }
+
+    async fn send_message_reliable(&self, message: &OwnedMessage) -> anyhow::Result<Transaction> {
+        let execution = self.execute(message).await?;
+        execution
+            .transactions
+            .into_iter()
+            .next()
+            .context("message was not executed")
+    }
+
+    async fn get_contract_state(
+        &self,
+        address: &StdAddr,
+        last_transaction_lt: Option<u64>,
+    ) -> anyhow::Result<ContractState> {
+        let shard_account = self.shard_account(address).await?;
+        let timings = get_gen_timings(self.origin.clock(), shard_account.last_trans_lt);
+
+        let Some(account) = shard_account.load_account()? else {
+            return Ok(ContractState::NotExists { timings });
+        };
+        if last_transaction_lt == Some(shard_account.last_trans_lt) {
+            return Ok(ContractState::Unchanged { timings });
+        }
+
+        Ok(ContractState::Exists {
+            account: Box::new(account),
+            timings,
+            last_transaction_id: LastTransactionId {
+                lt: shard_account.last_trans_lt,
+                hash: shard_account.last_trans_hash,
+            },
+        })
+    }
+
+    async fn get_config(&self) -> anyhow::Result<LatestBlockchainConfig> {
+        self.origin.transport().get_config().await
+    }
+
+    async fn get_transaction(&self, hash: &HashBytes) -> anyhow::Result<Option<Transaction>> {
+        let tx = self.state.lock().unwrap().transactions.get(hash).cloned();
+        match tx {
+            Some(tx) => Ok(Some(tx)),
+            None => self.origin.transport().get_transaction(hash).await,
+        }
+    }
+
+    async fn get_dst_transaction(
+        &self,
+        message_hash: &HashBytes,
+    ) -> anyhow::Result<Option<Transaction>> {
+        let tx = {
+            let state = self.state.lock().unwrap();
+            state
+                .dst_transactions
+                .get(message_hash)
+                .and_then(|hash| state.transactions.get(hash))
+                .cloned()
+        };
+        match tx {
+            Some(tx) => Ok(Some(tx)),
+            None => {
+                self.origin
+                    .transport()
+                    .get_dst_transaction(message_hash)
+                    .await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Context;
+    use futures_util::FutureExt;
+    use tycho_types::cell::{Cell, CellBuilder, HashBytes};
+    use tycho_types::models::{CurrencyCollection, IntAddr};
+    use tycho_types::num::Tokens;
+
+    use super::*;
+    use crate::contracts::blockchain_context::MessageBuilder;
+    use crate::contracts::tests::{code_cell, test_account, test_context};
+
+    /// Account which accepts any message and forwards the referenced one.
+    fn forwarding_account(address: &StdAddr, dst: &StdAddr) -> anyhow::Result<Account> {
+        let message = MessageBuilder::new_internal_in(
+            IntAddr::Std(address.clone()),
+            IntAddr::Std(dst.clone()),
+        )
+        .with_value(Tokens::new(1_000_000_000))
+        .with_bounce(false)
+        .build_cell()?;
+
+        // ACCEPT PUSHREF PUSHINT 1 SENDRAWMSG
+        let code = code_cell(&[0xf8, 0x00, 0x88, 0x71, 0xfb, 0x00], &[message])?;
+        Ok(Account {
+            address: IntAddr::Std(address.clone()),
+            ..test_account(code, Cell::empty_cell())?
+        })
+    }
+
+    fn shard_account(account: Account) -> anyhow::Result<ShardAccount> {
+        Ok(ShardAccount {
+            account: Lazy::new(&OptionalAccount(Some(account)))?,
+            last_trans_hash: HashBytes::ZERO,
+            last_trans_lt: 0,
+        })
+    }
+
+    #[test]
+    fn failed_cascade_keeps_state() -> anyhow::Result<()> {
+        let (context, transport) = test_context()?;
+
+        let a = StdAddr::new(0, HashBytes([0x11; 32]));
+        let b = StdAddr::new(0, HashBytes([0x22; 32]));
+        let c = StdAddr::new(0, HashBytes([0x33; 32]));
+        transport.set_account(a.clone(), shard_account(forwarding_account(&a, &b)?)?);
+        transport.set_account(b.clone(), shard_account(forwarding_account(&b, &c)?)?);
+        // State of the last hop can't be loaded
+        transport.set_account(
+            c.clone(),
+            ShardAccount {
+                account: Lazy::from_raw(CellBuilder::build_from(0xdeadu16)?)?,
+                last_trans_hash: HashBytes::ZERO,
+                last_trans_lt: 0,
+            },
+        );
+
+        let fork = ForkedChain::new(context);
+        let message = MessageBuilder::new_external_in(IntAddr::Std(a.clone())).build();
+        let send = |fork: &ForkedChain| {
+            fork.send_message(&message)
+                .now_or_never()
+                .context("send is not ready")
+        };
+        let get_account = |fork: &ForkedChain, address: &StdAddr| {
+            fork.get_account(address)
+                .now_or_never()
+                .context("get is not ready")?
+                .map(|account| account.expect("account exists"))
+        };
+
+        // Transactions on A and B are executed, but C fails
+        assert!(send(&fork)?.is_err());
+        for address in [&a, &b] {
+            let account = get_account(&fork, address)?;
+            assert_eq!(account.last_trans_lt, 0);
+            assert_eq!(account.balance, CurrencyCollection::new(10_000_000_000));
+        }
+
+        // ACCEPT DROP
+        let code = code_cell(&[0xf8, 0x00, 0x30], &[])?;
+        fork.set_account(Account {
+            address: IntAddr::Std(c.clone()),
+            ..test_account(code, Cell::empty_cell())?
+        })?;
+
+        let execution = send(&fork)??;
+        assert_eq!(execution.transactions.len(), 3);
+        assert!(execution.pending.is_empty());
+
+        let lts = execution
+            .transactions
+            .iter()
+            .map(|tx| tx.lt)
+            .collect::<Vec<_>>();
+        assert!(lts.windows(2).all(|pair| pair[0] < pair[1]));
+
+        for (address, tx) in [&a, &b, &c].into_iter().zip(&execution.transactions) {
+            let account = get_account(&fork, address)?;
+            assert_eq!(account.last_trans_lt, tx.end_lt);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn forked_context_sees_forked_state() -> anyhow::Result<()> {
+        let (context, transport) = test_context()?;
+
+        // ACCEPT DROP
+        let address = StdAddr::new(0, HashBytes([0x11; 32]));
+        let code = code_cell(&[0xf8, 0x00, 0x30], &[])?;
+        transport.set_account(
+            address.clone(),
+            shard_account(Account {
+                address: IntAddr::Std(address.clone()),
+                ..test_account(code, Cell::empty_cell())?
+            })?,
+        );
+
+        let fork = context.fork();
+        let message = MessageBuilder::new_external_in(IntAddr::Std(address.clone())).build();
+        let execution = fork
+            .send_message(&message)
+            .now_or_never()
+            .context("send is not ready")??;
+        let tx = &execution.transactions[0];
+
+        let get_account = |context: &BlockchainContext| {
+            context
+                .get_account(&address)
+                .now_or_never()
+                .context("get is not ready")?
+        };
+
+        // Accounts of the forked context have the forked state
+        let mut account = get_account(fork.context())?;
+        assert_eq!(account.last_transaction_id().map(|id| id.lt), Some(tx.lt));
+        let origin = get_account(&context)?;
+        assert_eq!(origin.last_transaction_id().map(|id| id.lt), Some(0));
+        assert_ne!(account.account().balance, origin.account().balance);
+
+        let changed = account
+            .refresh()
+            .now_or_never()
+            .context("refresh is not ready")??;
+        assert!(!changed);
+
+        // Executed transactions are served by the fork
+        let hash = account
+            .last_transaction_id()
+            .context("no transaction")?
+            .hash;
+        let found = fork
+            .context()
+            .transport()
+            .get_transaction(&hash)
+            .now_or_never()
+            .context("get is not ready")??;
+        assert_eq!(found.map(|found| found.lt), Some(tx.lt));
+        Ok(())
+    }
+}
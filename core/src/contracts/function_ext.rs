@@ -1,36 +1,74 @@
+use std::sync::Arc;
+
+use nekoton_utils::signature_context::SignatureContext;
+use nekoton_utils::signer::Signer;
 use num_traits::cast::ToPrimitive;
 use tycho_executor::ParsedConfig;
-use tycho_types::abi::{AbiType, AbiValue, Function, NamedAbiValue};
-use tycho_types::models::{Account, RelaxedMsgInfo};
+use tycho_types::abi::{AbiType, AbiValue, Contract, Function, NamedAbiValue};
+use tycho_types::models::{Account, IntAddr, OwnedRelaxedMessage, RelaxedMsgInfo, StateInit};
 use tycho_types::num::Tokens;
 use tycho_vm::OwnedCellSlice;
 
 use super::blockchain_context::{BlockchainContext, MessageBuilder};
-use super::local_executor;
+use super::events::{ContractExt, DecodedEvent};
+use super::local_executor::{self, ComputePhaseResult};
+use super::overrides::StateOverrides;
+use super::tracer::ExecutionTrace;
 use super::utils::get_gen_timings;
-use crate::error::ExecutionError;
+use crate::error::{AbiErrors, ComputeError, ExecutionError};
 use crate::models::GenTimings;
 
 pub trait FunctionExt {
     fn run_local(
         &self,
-        account: &mut Account,
+        account: &Account,
         input: &[NamedAbiValue],
         responsible: bool,
-        context: &mut BlockchainContext,
+        options: &RunLocalOptions<'_>,
+        context: &BlockchainContext,
     ) -> Result<ExecutionOutput, ExecutionError>;
 }
 
+/// Parameters of the external message used for local runs.
+#[derive(Default, Clone, Copy)]
+pub struct RunLocalOptions<'a> {
+    /// Signs the message body and fills the `pubkey` header.
+    /// The body is left unsigned if not set.
+    pub signer: Option<&'a dyn Signer>,
+    pub signature_context: SignatureContext,
+    /// `time` header in milliseconds. Context clock time is used if not set.
+    pub time: Option<u64>,
+    /// `expire` header in seconds. Never expires if not set.
+    pub expire_at: Option<u32>,
+    /// Records executed instructions into [`ExecutionOutput::trace`].
+    pub trace: bool,
+    /// Changes to the emulated environment.
+    /// Responsible calls get a balance of 100 000 native tokens unless overridden.
+    pub overrides: Option<&'a StateOverrides>,
+    /// Config parsed in advance, e.g. once for a batch of calls.
+    /// Ignored if the overrides change config params.
+    pub parsed_config: Option<&'a ParsedConfig>,
+}
+
 impl FunctionExt for Function {
     fn run_local(
         &self,
-        account: &mut Account,
+        account: &Account,
         input: &[NamedAbiValue],
         responsible: bool,
-        context: &mut BlockchainContext,
+        options: &RunLocalOptions<'_>,
+        context: &BlockchainContext,
     ) -> Result<ExecutionOutput, ExecutionError> {
+        let mut account = match options.overrides {
+            Some(overrides) => overrides.apply_to_account(account),
+            None => account.clone(),
+        };
+        let account = &mut account;
+
         let answer_id = if responsible {
-            account.balance.tokens = Tokens::new(100_000_000_000_000u128); // 100 000 native tokens
+            if options.overrides.and_then(|o| o.balance.as_ref()).is_none() {
+                account.balance.tokens = Tokens::new(100_000_000_000_000u128); // 100 000 native tokens
+            }
 
             match input.first().map(|token| &token.value) {
                 Some(AbiValue::Uint(32, number)) => {
@@ -54,42 +92,86 @@ impl FunctionExt for Function {
                 .with_body(self.encode_internal_input(input)?)?
                 .build()
         } else {
-            let (_, payload) = self
+            let IntAddr::Std(address) = &account.address else {
+                return Err(ExecutionError::InvalidAddressType);
+            };
+
+            let time = options.time.unwrap_or_else(|| context.clock().now_ms_u64());
+            let input = self
                 .encode_external(input)
-                .with_expire_at(u32::MAX)
-                .build_input_without_signature()?;
+                .with_address(address)
+                .with_time(time)
+                .with_expire_at(options.expire_at.unwrap_or(u32::MAX));
+
+            let payload = match options.signer {
+                Some(signer) => {
+                    let public_key = signer.public_key();
+                    let body = input.with_pubkey(&public_key).build_input()?;
+                    let signature = signer.sign(body.hash.as_slice(), options.signature_context);
+                    body.with_signature(&signature)?
+                }
+                None => input.build_input_without_signature()?.1,
+            };
+
             MessageBuilder::new_external_in(account.address.clone())
                 .with_body(payload)?
                 .build()
         };
 
-        let GenTimings { gen_utime, gen_lt } =
-            get_gen_timings(context.clock(), account.last_trans_lt);
+        let GenTimings {
+            mut gen_utime,
+            gen_lt,
+        } = get_gen_timings(context.clock(), account.last_trans_lt);
+
+        let mut params = context.executor_params().clone();
+        let mut config_overridden = false;
+        if let Some(overrides) = options.overrides {
+            if let Some(utime) = overrides.block_utime {
+                gen_utime = utime;
+            }
+            if let Some(rand_seed) = overrides.rand_seed {
+                params.rand_seed = rand_seed;
+            }
+            config_overridden = !overrides.config_params.is_empty();
+        }
 
-        let parsed_config = ParsedConfig::parse(context.config().clone(), gen_utime)?;
+        let parsed;
+        let parsed_config = match (options.parsed_config, options.overrides) {
+            (Some(config), _) if !config_overridden => config,
+            (_, Some(overrides)) if config_overridden => {
+                let config = overrides.apply_to_config(&context.config())?;
+                parsed = Arc::new(ParsedConfig::parse(config, gen_utime)?);
+                parsed.as_ref()
+            }
+            _ => {
+                parsed = context.parsed_config(gen_utime)?;
+                parsed.as_ref()
+            }
+        };
 
-        let params = context.executor_params_mut();
         params.block_unixtime = gen_utime;
         params.block_lt = gen_lt;
 
-        let compute_phase_result =
-            local_executor::execute_message(account, &message, params, &parsed_config)?;
+        let compute_phase_result = local_executor::execute_message(
+            account,
+            &message,
+            &params,
+            parsed_config,
+            options.trace,
+        )?;
 
         if !compute_phase_result.success {
-            return Ok(ExecutionOutput {
-                values: vec![],
-                exit_code: !compute_phase_result.exit_code,
-            });
+            return Ok(ExecutionOutput::new(vec![], compute_phase_result));
         }
 
         let mut output = vec![];
         if let Some(answer_id) = answer_id {
-            for msg in compute_phase_result.out_messages {
+            for msg in &compute_phase_result.out_messages {
                 if let RelaxedMsgInfo::ExtOut(_) = msg.info {
                     continue;
                 }
 
-                let slice = OwnedCellSlice::from(msg.body);
+                let slice = OwnedCellSlice::from(msg.body.clone());
                 let mut slice = slice.apply();
 
                 if !matches!(
@@ -107,12 +189,12 @@ impl FunctionExt for Function {
                 }
             }
         } else {
-            for msg in compute_phase_result.out_messages {
+            for msg in &compute_phase_result.out_messages {
                 if let RelaxedMsgInfo::Int(_) = msg.info {
                     continue;
                 }
 
-                let slice = OwnedCellSlice::from(msg.body);
+                let slice = OwnedCellSlice::from(msg.body.clone());
                 let slice = slice.apply();
 
                 let output_id = slice.get_u32(slice.offset_bits())?;
@@ -127,15 +209,65 @@ impl FunctionExt for Function {
             }
         };
 
-        Ok(ExecutionOutput {
-            values: output,
-            exit_code: !compute_phase_result.exit_code,
-        })
+        Ok(ExecutionOutput::new(output, compute_phase_result))
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct ExecutionOutput {
     pub values: Vec<NamedAbiValue>,
+    /// Whether the compute phase has succeeded.
+    pub success: bool,
     pub exit_code: i32,
+    /// Optional argument passed to the exception.
+    pub exit_arg: Option<i32>,
+    /// Whether the contract accepted the message.
+    pub accepted: bool,
+    pub gas_used: u64,
+    pub vm_steps: u32,
+    /// Output of the VM debug primitives.
+    pub vm_log: String,
+    /// Code and data of the account after the call.
+    pub new_state: StateInit,
+    /// All outgoing messages, including the ones used for `values`.
+    pub out_messages: Vec<OwnedRelaxedMessage>,
+    pub trace: Option<ExecutionTrace>,
+}
+
+impl ExecutionOutput {
+    /// Decodes events emitted during the execution.
+    pub fn events(&self, contract: &Contract) -> anyhow::Result<Vec<DecodedEvent>> {
+        contract.decode_events(&self.out_messages)
+    }
+
+    /// Returns the decoded exit code if the compute phase has failed.
+    /// Custom codes are named using `errors` if specified.
+    pub fn error(&self, errors: Option<&AbiErrors>) -> Option<ComputeError> {
+        (!self.success)
+            .then(|| ComputeError::new(self.exit_code, self.exit_arg, self.gas_used, errors))
+    }
+
+    /// Converts a failed execution into [`ExecutionError::ComputePhaseFailed`].
+    pub fn into_result(self, errors: Option<&AbiErrors>) -> Result<Self, ExecutionError> {
+        match self.error(errors) {
+            Some(error) => Err(ExecutionError::ComputePhaseFailed(error)),
+            None => Ok(self),
+        }
+    }
+
+    fn new(values: Vec<NamedAbiValue>, result: ComputePhaseResult) -> Self {
+        Self {
+            values,
+            success: result.success,
+            exit_code: !result.exit_code,
+            exit_arg: result.exit_arg,
+            accepted: result.accepted,
+            gas_used: result.gas_used,
+            vm_steps: result.vm_steps,
+            vm_log: result.vm_log,
+            new_state: result.new_state,
+            out_messages: result.out_messages,
+            trace: result.trace,
+        }
+    }
 }
@@ -1,28 +1,119 @@
 use tycho_types::cell::{Cell, CellBuilder};
 use tycho_types::models::{
-    Account, ComputePhase, IntAddr, MsgType, OutAction, OutActionsRevIter, OwnedMessage,
-    OwnedRelaxedMessage, ShardAccount, Transaction,
+    Account, ComputePhase, IntAddr, MsgInfo, MsgType, OutAction, OutActionsRevIter, OwnedMessage,
+    OwnedRelaxedMessage, ShardAccount, StateInit, StdAddr, Transaction, TxInfo,
 };
 use tycho_types::prelude::{CellFamily, Store};
 
 use tycho_executor::phase::{ComputePhaseContext, TransactionInput};
-use tycho_executor::{ExecutorParams, ParsedConfig};
+use tycho_executor::{ExecutorInspector, ExecutorParams, ParsedConfig};
 use tycho_types::num::Tokens;
 use tycho_vm::OwnedCellSlice;
 
+use super::tracer::ExecutionTrace;
 use crate::error::ExecutionError;
 
 pub struct ComputePhaseResult {
     pub exit_code: i32,
+    /// Optional argument passed to the exception.
+    pub exit_arg: Option<i32>,
     pub success: bool,
+    /// Whether the contract accepted the message.
+    pub accepted: bool,
+    pub gas_used: u64,
+    pub vm_steps: u32,
+    /// Output of the VM debug primitives.
+    pub vm_log: String,
+    /// Code and data of the account after the compute phase.
+    pub new_state: StateInit,
     pub out_messages: Vec<OwnedRelaxedMessage>,
+    /// Executed instructions, if tracing was requested.
+    pub trace: Option<ExecutionTrace>,
 }
+
+/// Fees paid by the account for a transaction.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TransactionFees {
+    pub storage_fee: Tokens,
+    pub gas_fee: Tokens,
+    /// Inbound message import fee and the forwarding fees carried by the
+    /// outbound messages to pay for their next hops.
+    ///
+    /// The share of the forwarding fees collected by the current validators
+    /// is a part of `action_fee`, so the fees do not overlap.
+    pub fwd_fee: Tokens,
+    /// Fees collected in the action phase, mostly the first-hop share
+    /// of the outbound messages forwarding fees.
+    pub action_fee: Tokens,
+    /// Total fees collected by the validators.
+    pub total_fee: Tokens,
+    /// Balance required to pay all fees and the value of the outbound messages.
+    pub required_balance: Tokens,
+}
+
+impl TransactionFees {
+    pub fn from_transaction(tx: &Transaction) -> Result<Self, ExecutionError> {
+        let TxInfo::Ordinary(info) = tx.load_info()? else {
+            return Err(ExecutionError::Other(anyhow::anyhow!(
+                "unexpected transaction type"
+            )));
+        };
+
+        let storage_fee = info
+            .storage_phase
+            .map(|phase| phase.storage_fees_collected)
+            .unwrap_or_default();
+
+        let gas_fee = match info.compute_phase {
+            ComputePhase::Executed(phase) => phase.gas_fees,
+            ComputePhase::Skipped(_) => Tokens::ZERO,
+        };
+
+        let action_fee = info
+            .action_phase
+            .and_then(|phase| phase.total_action_fees)
+            .unwrap_or_default();
+
+        let total_fee = tx.total_fees.tokens;
+
+        // Whatever is not explained by the phases was paid for the inbound message import.
+        let import_fee = total_fee
+            .checked_sub(storage_fee)
+            .and_then(|fee| fee.checked_sub(gas_fee))
+            .and_then(|fee| fee.checked_sub(action_fee))
+            .unwrap_or_default();
+
+        let mut out_fwd_fee = Tokens::ZERO;
+        let mut required_balance = total_fee;
+        for item in tx.out_msgs.values() {
+            let message = item?.parse::<OwnedMessage>()?;
+            if let MsgInfo::Int(info) = message.info {
+                out_fwd_fee = out_fwd_fee.saturating_add(info.fwd_fee);
+                required_balance = required_balance
+                    .saturating_add(info.value.tokens)
+                    .saturating_add(info.fwd_fee)
+                    .saturating_add(info.ihr_fee);
+            }
+        }
+
+        Ok(Self {
+            storage_fee,
+            gas_fee,
+            fwd_fee: import_fee.saturating_add(out_fwd_fee),
+            action_fee,
+            total_fee,
+            required_balance,
+        })
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn execute_message(
     account: &Account,
     message: &OwnedMessage,
     executor_params: &ExecutorParams,
     config: &ParsedConfig,
+    trace: bool,
 ) -> Result<ComputePhaseResult, ExecutionError> {
     let mut builder = CellBuilder::new();
     message.store_into(&mut builder, Cell::empty_context())?;
@@ -34,16 +125,36 @@ pub fn execute_message(
         return Err(ExecutionError::InvalidAddressType);
     };
 
+    // Internal messages are accepted by the value they carry. External ones
+    // are executed within the gas credit, as the network would do, to see
+    // whether the contract accepts them.
+    let is_external = matches!(message.ty(), MsgType::ExtIn);
+
     let mut state = executor.begin(std_addr, Some(account.clone()))?;
     let received_message = state.receive_in_msg(in_msg_cell)?;
 
-    let compute_phase_result = state.compute_phase(ComputePhaseContext {
-        input: TransactionInput::Ordinary(&received_message),
-        storage_fee: Tokens::ZERO,
-        force_accept: true,
-        stop_on_accept: false,
-        inspector: None,
-    })?;
+    let mut vm_log = String::new();
+    let mut inspector = ExecutorInspector {
+        debug: Some(&mut vm_log),
+        ..Default::default()
+    };
+
+    let mut compute_phase = || {
+        state.compute_phase(ComputePhaseContext {
+            input: TransactionInput::Ordinary(&received_message),
+            storage_fee: Tokens::ZERO,
+            force_accept: !is_external,
+            stop_on_accept: false,
+            inspector: Some(&mut inspector),
+        })
+    };
+    let (compute_phase_result, trace) = if trace {
+        let (result, trace) = ExecutionTrace::record(compute_phase);
+        (result?, Some(trace.map_err(anyhow::Error::from)?))
+    } else {
+        (compute_phase()?, None)
+    };
+    let accepted = compute_phase_result.accepted;
 
     let executed_compute_phase = match compute_phase_result.compute_phase {
         ComputePhase::Skipped(result) => {
@@ -52,6 +163,11 @@ pub fn execute_message(
         ComputePhase::Executed(executed_result) => executed_result,
     };
 
+    // The phase is never successful without `ACCEPT`, while local calls
+    // of getters don't accept messages, so these are checked by the exit code
+    let success = executed_compute_phase.success
+        || (!accepted && matches!(executed_compute_phase.exit_code, 0 | 1));
+
     let mut msgs = Vec::new();
     let actions_slice = OwnedCellSlice::new_allow_exotic(compute_phase_result.actions);
     let out_actions_iter = OutActionsRevIter::new(actions_slice.apply());
@@ -65,8 +181,15 @@ pub fn execute_message(
 
     Ok(ComputePhaseResult {
         exit_code: !executed_compute_phase.exit_code,
-        success: executed_compute_phase.success,
+        exit_arg: executed_compute_phase.exit_arg,
+        success,
+        accepted,
+        gas_used: executed_compute_phase.gas_used.into_inner(),
+        vm_steps: executed_compute_phase.vm_steps,
+        vm_log,
+        new_state: compute_phase_result.new_state,
         out_messages: msgs,
+        trace,
     })
 }
 
@@ -77,15 +200,43 @@ pub fn execute_ordinary_transaction(
     config: &ParsedConfig,
 ) -> Result<Transaction, ExecutionError> {
     let is_external = !matches!(message.ty(), MsgType::Int);
-
-    let optional = shard_account.load_account()?;
-    let Some(account) = optional else {
-        return Err(ExecutionError::AccountDoesNotExist);
-    };
-    let address = account.address.as_std().unwrap();
+    let address = message_destination(message)?;
 
     let executor = tycho_executor::Executor::new(executor_params, config);
     let uncommited = executor.begin_ordinary(address, is_external, message, shard_account)?;
     let tx = uncommited.build_uncommitted()?;
     Ok(tx)
 }
+
+/// Executes all transaction phases and returns the transaction
+/// together with the updated account state.
+pub fn emulate_ordinary_transaction(
+    shard_account: &ShardAccount,
+    message: &OwnedMessage,
+    executor_params: &ExecutorParams,
+    config: &ParsedConfig,
+) -> Result<(Transaction, ShardAccount), ExecutionError> {
+    let is_external = !matches!(message.ty(), MsgType::Int);
+    let address = message_destination(message)?;
+
+    let executor = tycho_executor::Executor::new(executor_params, config);
+    let uncommited = executor.begin_ordinary(address, is_external, message, shard_account)?;
+    let output = uncommited.commit()?;
+
+    let tx = output.transaction.load()?;
+    Ok((tx, output.new_state))
+}
+
+/// Returns the receiver of the inbound message. The account itself
+/// may not exist yet, e.g. when the message deploys it.
+pub(crate) fn message_destination(message: &OwnedMessage) -> Result<&StdAddr, ExecutionError> {
+    let dst = match &message.info {
+        MsgInfo::Int(info) => &info.dst,
+        MsgInfo::ExtIn(info) => &info.dst,
+        MsgInfo::ExtOut(_) => return Err(ExecutionError::InvalidAddressType),
+    };
+    match dst {
+        IntAddr::Std(address) => Ok(address),
+        IntAddr::Var(_) => Err(ExecutionError::InvalidAddressType),
+    }
+}
@@ -1,21 +1,48 @@
+pub mod batch;
 pub mod blockchain_context;
+pub mod config_cache;
+pub mod contract_handle;
+pub mod deploy;
+pub mod events;
+pub mod fork;
 pub mod function_ext;
 pub mod local_executor;
+pub mod overrides;
+pub mod stack;
+pub mod storage;
+pub mod tracer;
 pub mod utils;
 
 #[cfg(test)]
 pub mod tests {
-    use crate::contracts::blockchain_context::BlockchainContextBuilder;
+    use crate::contracts::blockchain_context::{
+        BlockchainAccount, BlockchainContext, BlockchainContextBuilder, MessageBuilder,
+    };
+    use crate::contracts::function_ext::RunLocalOptions;
+    use crate::contracts::local_executor::TransactionFees;
+    use crate::contracts::overrides::StateOverrides;
+    use crate::contracts::stack::StackResult;
+    use crate::error::ExecutionError;
+    use crate::models::LastTransactionId;
     use crate::transport::SimpleTransport;
+    use anyhow::Context;
+    use ed25519_dalek::SigningKey;
+    use futures_util::FutureExt;
+    use nekoton_utils::signature_context::SignatureContext;
     use num_bigint::BigUint;
     use num_traits::Zero;
     use std::sync::Arc;
+    use tycho_executor::TxError;
     use tycho_types::abi::{AbiHeaderType, AbiType, AbiValue, AbiVersion, Function};
     use tycho_types::boc::Boc;
+    use tycho_types::cell::HashBytes;
     use tycho_types::models::{
-        BlockchainConfig, IntAddr, SizeLimitsConfig, StdAddr, StdAddrFormat,
+        Account, AccountState, AccountStatus, BlockchainConfig, CurrencyCollection,
+        GlobalCapabilities, GlobalCapability, IntAddr, OptionalAccount, ShardAccount,
+        SizeLimitsConfig, StateInit, StdAddr, StdAddrFormat, TxInfo,
     };
-    use tycho_types::prelude::{CellBuilder, Load};
+    use tycho_types::num::Tokens;
+    use tycho_types::prelude::{Cell, CellBuilder, CellFamily, Lazy, Load};
     use tycho_vm::{tuple, OwnedCellSlice, SafeRc};
 
     #[test]
@@ -46,7 +73,7 @@ pub mod tests {
             .with_transport(Arc::new(transport))
             .build()?;
 
-        let mut account = context.get_account_from_cell(account_cell.as_ref())?;
+        let account = context.get_account_from_cell(account_cell.as_ref())?;
 
         let values = vec![
             AbiValue::Uint(64, BigUint::zero()).named("_index"),
@@ -122,10 +149,8 @@ pub mod tests {
         anyhow::bail!("unsuccessful exit code: {}", result.exit_code);
     }
 
-    #[test]
-    fn gen_test() -> anyhow::Result<()> {
-        let function = qube::functions::get_details();
-
+    /// Account of the contract described by `abi.json`.
+    pub(crate) fn qube_account() -> anyhow::Result<BlockchainAccount> {
         let config_cell = Boc::decode_base64("te6ccgICAuUAAQAAbr4AAAFAVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUAAQIDzUACcgACAgEgAAUAAwEDp0AABACjAgAAAAUAAAAgAAAAAgAAAAUAAAEsAAACWAAAA+gAAAfQAAAD6AAAJxAAA6mAAAAAFAAAAGQAAAABAAAAAgAAD6AAAA+gAAAAAwAAAAEAAAAAwAIBIAAPAAYBAbkABwEBwAAIAgPCCAAMAAkCASAACwAKAEK/lKUMGoSaR0IhTnUZd68mgmnm7q4GTOgAY0rOokHUMNMAQr+8Q98gVqvuTBpEP7/P7eC6kNIUx3MiFn/AjOSJIMF8GwIBIAAOAA0AQr+Wb9ay5on7vaU3/xXryrjQlgMWezGVIPDk0BurLnNeSwBCv4ABabBCw3liAn5Y3g26oLhfXQMvN9gzPjzf3MeRiuAKAgFIAT0AEAEBSAARASsSZ+ET/GfiE/wAlgBkD////////7rAABICAsgAPgATAgFiAB8AFAIBSAAYABUCAUgAFwAWAJsc46BJ4oFpihIcravWGGeWAw1arosnu+1Zc9OHk6G0TPAKBFbWQAMy3e1/Rc/Zp4fK1e4ETVoOXXQ6/0fWDmpkrF/FCaEI2jujnniKIWAAmxzjoEniqJveRVQp6C6sB5RJlq2bCJrLEU9YrAO9c/DytzY+rvwAA1i5mrYGAC1CxxSCKITnmAszPOiKTnAYkLzz3rQC6iocExcfv7BO4AIBIAAcABkCASAAGwAaAJsc46BJ4oAP16lGePOzKrKCMKyQslRJN32OlQdbCxRCuunaV/n2gANy3SNwWvDhZnnbg89C07gaYRku7WcqPFL9hHdysy+ZmJHQYzu+JKAAmxzjoEnisBy7x/f6U65QjcmSv64VM6XEtICtl6rDnzd5zY2PboRAA5Mx8V+acdQX2KRwCVqFaHcXHgNZORNxVaACvRD/LHzFQ7vL+HHqIAIBIAAeAB0AmxzjoEnipKxz0JCp0JeGv9nbKcV82x5yDrn7kxXfz4eW1U28X6cAA5a9BHuLQLrW9JfRGqU4isz/Jplp2v3ZRfSBmds2maHaUnq4yq/soACbHOOgSeKRNfI4RZrfY52/TdFieJ9Jy8IbD7jRciMqeOXnXjebVUADl58t3P7YlzzDkF8DMhWwn4yR1xFuHF+q8zIhxzFtpP4O5NamRrxgAgEgAC8AIAIBIAAoACECASAAJQAiAgEgACQAIwCbHOOgSeKcsjSuR9XFkNjNSMyDmsPfyuU5agHSuolWfO2Guu5UVUADmXViZa2JoCSGzzjF+wvX44ZneF6VyhfcWCZL5SPJIg7w1lH5sTggAJsc46BJ4rPVofHT10hc1tJwCyqMOu2eB+YFAJW7CIO5Ife9vKQRAAOZtR9jxq4G/5hnVXbLb+FC87VOeJ93Me6gOFwfRcSIOmgXYYZ+eiACASAAJwAmAJsc46BJ4pISc6jkcroy61jQYl6Jdk8ZfhWyl8mCS3dxI0fb8uVxgAOZ0AoDPAgDtgjRBlDI/RanM+o+Lr2F/B+gTiZC14CIOpuh1kPR+GAAmxzjoEnitqGDmR9uKxy/PVziXZOA6OMXHrp3J212gjrJ2RNnqmZAA5pIxdBKuE5duZ/y03EI2o1/GdHPzKXaAf41edK/2B+avqt9LBuc4AIBIAAsACkCASAAKwAqAJsc46BJ4pLYKmpjhQaDxYGXPAl4WMMR2lAo+9QksuqnjHUhNvtqgAOaeXSNBjcUP215NFduTYhAvdPjIzOsyV5C15Dm9FQMqMDIKZOpKGAAmxzjoEnijcjJ56DEHin45H4TiNt88eqC3q/Dm3wzfhAiy3Z6vKrAA5zBg4jGToS3KqfacTQgXfodMInITqDLKobTG1bkur2SgxAvzXMEoAIBIAAuAC0AmxzjoEniiumq2GTNjc84bLIVV1f71aaDHw3FM8rhIRZuUVecT8SAA6fqIBfogZbuizYxzWLKaYVcoWzC9R2IWOprfKx1PWCRpZsl7kKL4ACbHOOgSeKzQIeiXtChmtgLnGOvgEbZQRrAZYnbV9nQ/CUh29chDAADqAiFDkiAc8EFRL/nsFxTuHEdwy6A3m+OyOstrcXqiOtdGWaMBzRgAgEgADcAMAIBIAA0ADECASAAMwAyAJsc46BJ4pxefW2Cuycq6X/WhZAD/fxzBX+L6C8l83iuO1N8DSgMgAOsCHx1iE2gZx7q3dxDJV78ozR4eCszFlTtgkCtJyQHwiEr0Y0wCSAAmxzjoEnihKDkpTbw8CauKyRN7//y+UNBYcLPBiu+v9tciQTi5UoAA7R6s+MeFXLGten8pG7WIAsipsCdhSPddXNAfxTic9hMi9njl3WrIAIBIAA2ADUAmxzjoEnirYZ7ZVXtnZLiCtPWTBM4eScfU/QKd1oOQ1dWMP7sXwrAA7TipDsrrTIdnM2mpNpnZMuMGZEva3BW8+8zmqsGrJLZ2VWBaud1YACbHOOgSeKCqqaGfaGfA6MMSjna2+96b2dun0YlcM4ukQ/4AAN9oMADyB/4M5pBTRM+A91IdQDwEMHjsEeBBDpN9hqhhVE5uUWVjN0YTB5gAgEgADsAOAIBIAA6ADkAmxzjoEninoq6Qyz11BJhS8Pb5vvdpIMBZCJmFNNA1LiCKDlsv0jAA8vk9WzPu3Wl/VZRg1DLQbH8wRNV4wT6vlSfEczXkHQx3UhM/QOrIACbHOOgSeKq958RdsLdXorRsQorrE+BLCYjKP9ibX2reu4XflAA+wAD0yxVBIlf2RMV7lXAe+ELC7WBygcLUMOKlrERM32Mk2vuUtl4bPygAgEgAD0APACbHOOgSeKdMd4ClW9IUV8nr4nBoiPSFzSWfa7zaT3lxLttXe9lYAAD1+tD0K9iE7cNsCWqH1/nN2AWPJqI3F2iUByyRNr41kzYHwNpMc6gAJsc46BJ4pvy1td0iICtEkCJz6OrGRj6as9EtY0IvrMN9h79v+wmAAPaOfZoAgGCU6zcu3CpL26r58TNwUQQEnc8k6OHheg8Ce37miTnC2ACASAAvgA/AgEgAH8AQAIBIABgAEECASAAUQBCAgEgAEoAQwIBIABHAEQCASAARgBFAJsc46BJ4rFLRROU+yrUMazFfz6eGBOZDmVQGocpMmdwndqm4a0SQAPdC7qubau0kMt342oPZoVEindVghPxhIoUuUSdgZqiu+qM+n9YKGAAmxzjoEnilwyd2zaGpmWjL+dvPSuWI7eE1JkzURKReZEpcn9j9ReAA+ZvbgXsSIEf4SCbPoPY4M2advoysYQhqsQeuv6Cz0AJR4Kaj663oAIBIABJAEgAmxzjoEnimfyeRAQCf2axtgshMksICBn29N+7XOhaK6jxlCiuqzgAA+cQm1KaDOsttTW7it6ld9ZxPyFm11YTQ7chgrdZ6A9z84kHepbJIACbHOOgSeKGSxNowUml9CV8v6nXKtvUrm9jf623BkaeUkJqfguCsIAD6QgvgDi3dtQT4mgAM91tIIL8eMQQ50XR9fsSQzsDV2rSUAC9fY+gAgEgAE4ASwIBIABNAEwAmxzjoEnitkn/y8fgTUnsYE+4b0tnwAOcfmROEWquGYNLuLO+52YAA/HzWOlTERt4V+ziCSqlf53raERJKRqsJjOorPE43joGCoRZ4TxnoACbHOOgSeKGO9FEWL8kWL7YFwBOsrQNTYjy9fgFOBl8ri28+6nWH0AD8mfeKOrrbN1SJx5xms6mpfZ5QETW59oziVVPIpi7nT3FWgmowPugAgEgAFAATwCbHOOgSeKwG0qBmOYGSlPkri+ozvgP2MZxK8IWmkhiJV4TRUyOp8AEIyJUJQOwxbR5H9m25kn7wuDl1yYPmMCJ9i+L1m8JemMp6zMYHEZgAJsc46BJ4rznDZdyOp7l98W1NENpjzr8rZJ9LbzyXxatusyylo0lQAQk8rPcnBAzJZ4vERNQ4Pa8muDGiE2uNCdgvtSC0BdJ7GQyTWoFXOACASAAWQBSAgEgAFYAUwIBIABVAFQAmxzjoEnik5a9B5jwYxQ6j5dRxcCeryArpLmKh+lfbATAXb43BDaABC/I90/M8r+YfNNft5X5OQ0Jzm0UjYJY0mImEkBDOGdjDHV5PMeF4ACbHOOgSeKC6rwNs5YL5+whp+1dACw2Q3TAususlcTwR/E0DyQFVUAEOgOjR+YRY40Oll0qO8LmkcRXk12UDyPmiiRs7L82xGkboNlA0fWgAgEgAFgAVwCbHOOgSeKaRTcJAnRYELli0eIuodwPcMJkRC2YvLdN2FRCzyOy4wAEPluqMGPSgdCnL+wB3hhTLuZVOBN1hftEhcQbTTEESc7qWQtrwcGgAJsc46BJ4r33ihv8dlmsFXW4K9n1bWvhJ7R/U6p9Fh76pZXRSPcbgARAORO7+8LsVKNp5cev64sR/41zdljtz9phCyV+gdMVSN9a+mhe1GACASAAXQBaAgEgAFwAWwCbHOOgSeKtkVVivgPv0dekUkQkyUhv2ZU6gSpKetxKYZpuZe5teAAETEXHdHuJHjgSXL0ceZtLsqFUw89FOzdPhWpCYcuF3HeTsw24CGFgAJsc46BJ4pmzz6lbolaNCYCeHZqSackCq7nypAQdwG5jtCwJYAJrgAROdommvGLM4cwcvoxG9rdEmdNmK/j6wDgQ/gKbPA4FsVMQZIJbF+ACASAAXwBeAJsc46BJ4pFvwNk7+5sxywEktfTGjHaxMkT2gwK788pSzg7PyCBEwARXunCdGJNkiL+6xogNBz4YHnziRxTwNZIZkZhDFZNZJ+J/uI/FRCAAmxzjoEnihk0s/72co1tTDxdZAykjqN4u7PKWXyJpUiJ2h8CPMcBABFhTyGkV0LfaMFalnCCSi4QFHxaNwpN+pdHvK86jj9P9zWugW56W4AIBIABwAGECASAAaQBiAgEgAGYAYwIBIABlAGQAmxzjoEnihn6hMs0XzVjYJxrwp8ILU9WIelgICpyPKqCFn9wWZiwABF54455sdhf+lYJ5xsmf/wu1w31zo2c2VkYQEx1G4H/bFPFLPhVgYACbHOOgSeKf7avjI5djDIZX+nr0YittK9OWG88gEc7PTVZ7JKJM68AEZMZRk1bsG7Zm/aB1FIQXISi7c0iWX6iDC8AYjPc7aClTqxkULn/gAgEgAGgAZwCbHOOgSeKM8Zw4GsOkQFrP1VHGHmfyw2baqkJvtpERWZYvecWLWoAEZjXS2qQ01DVE32GgYV+SHc/18WLlJtHzVl+K4DfkR/+ZSrqhhVGgAJsc46BJ4qTW+gdfn3YgNQhLYhPedQiShYV47b3pkM0Chdg23BMZwARmTloC1h82iAUhder94oFeNy3qGDwSFKb3KkMKwxDboDbETMfjHqACASAAbQBqAgEgAGwAawCbHOOgSeK3VVHbAIip1KPaupBp7gpubNPy/2xsQwBZYa4EdHtCVkAEbD5QclXfYHTxHs3d9NHFA1LSVHRiZAchm4VAzUFMg0vUQG59L1ogAJsc46BJ4pdOP32fI+EhOAQup/ZHfItC8hSKzgSD/y6LRx6+HKy1gARyP2N7sbEfNN5leuhfveykUE2Zkntj3/fDPk8E98mYoIdE3vNg1eACASAAbwBuAJsc46BJ4qv2IF1SmwacSrmq6zKiKToA90MusHo0rcbTC7NcGB21gAR2vvNXEXUV0SagerC07SyYkY16vdgvT5D92QYXt2bn5sLcVEpdX2AAmxzjoEnir45WjNCTGzdIq3ExUvGSRmNySKimLwRIda2oK42xvZwABHk4kuvpYfSD2ik8HOq7h0fPb5+LElR4npU44MgvBA6GljhfXeXEYAIBIAB4AHECASAAdQByAgEgAHQAcwCbHOOgSeK/qaSuFf5yTAzCECk/ZoCcwdL/9FjsFERHMZTKekaR8AAEfENN4t2BrrxfKXURdxWEi9BYoN+bny4JfYWy4AYSb84BPaDtCDEgAJsc46BJ4oUdNpzMf1Ywxo77dO3TlprBcam6cUKBHZft9kD74n4AwASCVeB8PkHqUIxppf4v5ZBa739Xzpf8NwES4z5wb2QckvH3nEOf5+ACASAAdwB2AJsc46BJ4pDtmJ/QW9BnT40TdHexzoN8qrzePsR7DgdDp/efEQVAgASG1CPah49fg5TwLxW2b7awpfkCiF65hIUZlsqeeBwtzW9X4ZJ1MiAAmxzjoEniosYOZePvGcLQ+TvGVfyp54DqbAnN0JNTHtGutfDuj/QABIqNb0l+uS9SuJ6ZGsk8gqtQ7qZjqzAYREBkv68cFR18zXWDo4WW4AIBIAB8AHkCASAAewB6AJsc46BJ4ognrONtRiCchYX/Tid1oHWR6JmK1kciBlm5rUlQXRCVAASOHL1IgQmYv571q81Ajk//zX4iBcrftp9DXHAic9u9BFoOHAGsP+AAmxzjoEnimdDysBmDJ8FAkc2z2X2bX7cW1Om1l/ZOJspFtDXaDcPABJELrWU4sc6OCuhynI7kvWxx6nBm3m62nuWycblpk/iO8B+M3yYaoAIBIAB+AH0AmxzjoEniiqAJZAvc9KM35L47zhgs+1aoC/1Vbjw7CxyKTrEjI+YABJTyRFYI4CaF+GWNKegw2alV45K/P9FLeXOtfXO5fWIxKZMxuLTMIACbHOOgSeKJmFmOSo9DcqnZOcgAlWncxGTZ+ZxbBd7/sU/fAN8TXYAElr4DU4hZY2YJffj73gIrVAlz/B4drtbNYVUj4yiViNcKPMW5c9XgAgEgAJ8AgAIBIACQAIECASAAiQCCAgEgAIYAgwIBIACFAIQAmxzjoEnik5DCMZpPk39QfXIAHLA4ZEvxcUlBFZ8Nck83nYTdTJaABJzWUoBIn3BvZbM6PfEjFOyFiFfx8Z2/9CXfJn8lYuARH0XImo7XIACbHOOgSeK809qQuStKDaymWtnyyZoYQlKwjw4feztZFh0LmH8uEMAEnuCJ278txvlXPEghv+nCM6wCnVydhZ5XhfvtuYALESZBmTV6xqUgAgEgAIgAhwCbHOOgSeKBd3rknwbX20r68sQbfPPBDQtsRNCkUswSYM7LR1akUUAErcp9pR+gG07dTxDvg224uN09KwzChdu+Fe11L57WK9v5Ol2+5kWgAJsc46BJ4pnGvNGCw5cBefa65SDRV6HO4RduSNgXTDz1lWIT7RSDAASt3FPfvmfmH+KZMc+DYw7LkVdbvkU88X2FNau5Iak9T0BuxeFtqWACASAAjQCKAgEgAIwAiwCbHOOgSeKOVih4QmuaaKfxvPDZfnRqkNrtP5wZgVUqHFF8ntskR8AEsu2Ry3SHbDPj9wmu1JnM3xgESwY92DYs7XvXqfsRfiuObqe0j8tgAJsc46BJ4qlicvKQbD0v3tlqA/Ch6sUvL0hVg36iqLBVPYVNVE8aQAS4dHpzHZKN/1zu+YvtoMyiN8CVpSeuXw+ESB8g8fFvGVzRGrFEK6ACASAAjwCOAJsc46BJ4rHD5bvp2filXmeERyxs2g5pRCXXdu5c9GryE5twAX96gATFGW7lwkVlDqHaSaWTfjjtkJUKqpYn98UF79jIac09TOCgmFubEaAAmxzjoEnisB22D5t58rVHwbkh3U6D+FkGIbitcT6fiRjF8Pz2eX1ABMaLE6qQlUrpj9fdJk15fu/FdLwkzR5yyVHtNMHvxMPogH8Uv98lYAIBIACYAJECASAAlQCSAgEgAJQAkwCbHOOgSeKRl0LNkzXG5pCQk9A+ofYn2lVg3uV2+RvBiwbEThCOJ8AEyydTvMtp1JbmyKpXpIL1i2fDC6QclrRJjlQ++Qum7PzSGX9/WJMgAJsc46BJ4rdqDv822Zwf57n9l/+zulhIknDG+ffuwPArNLkcnsiwgATh9ynX64FJo1pNZcMaORYJWQOA3vNLMEPQWo5Kvn6oQyTFtFUPXSACASAAlwCWAJsc46BJ4o7eQHSPsXztYROxROVgeAV+/kCRTan7xR2wY9bCH1rRQATsAF6gUbMSP4iUm3nPfDC2wbi63BdFtk7P1VoApMiterhUuojegSAAmxzjoEniisRDgq8yCDkCCv/7NhPD/7kBQSRU0IRlYG49iAMxOwKABPhWTnPXOH+PHIc33k3jkbc2RY134vCi6b0rywBlrmrxeKi6H3+bYAIBIACcAJkCASAAmwCaAJsc46BJ4omHn9U8M+1EecOnDjYrcPB9PH1gL2nhd4ppAljt1wMCgAUAky28JZhj0hJoBkMax8Zl2jpbhtanTHW5/y97gW974A4OGLtPbKAAmxzjoEnitHRWC1inwQknN10P3tullTEJOx9gGC9G1s6nE4No3weABRu0nHkQEXDSQNA2LBBOxtrYG5yaCzqpjuaMCaljwAvj7XxpeY3aIAIBIACeAJ0AmxzjoEniisdNq9vYYz1daMQx1Ca/x9nktngkdHdxmCwR0Gbvk1aABSNaCJrfRea/tPnTRhtpyUrSol/abWvtewr8tN1LJ//0RCJ79aEAIACbHOOgSeKuXHpmHNP0C+AHOqurNEdZKEpP9y6Kf3JrSSTMqlb3CEAFOIZa7Q1P4Q+uZMk8EBNQjDPMNnD+nyPqzwB/xGOtVR8NYzIJ6MPgAgEgAK8AoAIBIACoAKECASAApQCiAgEgAKQAowCbHOOgSeK3HqLu3HpT7+/iJToDdEgi12cKF5/NjuD9JNhYnDBdGoAFTsGvZaZxE1/FPdbf38UzMll6mKm9NyOy46hx8ZpeZF/XsBQ63nRgAJsc46BJ4ofa72OPu62yxArJmwFZc5kVLeK7fD/mac1aUufixLYvAAVbWOHKirWd5rqywMW/m9TP9Ctz4lirlh1NLperybo0gTrlUH5+0GACASAApwCmAJsc46BJ4p34Djgq9ZWl9gHXFUTT6L0Os8OKAsN6kt+J1is/Lzj1QAXFZO5BO/lDzGRdyt4XbgEFjakQbhoBoul9S0lCI2WMsCf2ci9TOOAAmxzjoEnig2++rJqP2+Z8rat2Al9LAAp9gj88mTKoJCXuxnaQfThAB+5mrLnYdYFS6gw4Kk4cjHiSsvz1qOECb/QZ3KNqmidLdM6Fqad/4AIBIACsAKkCASAAqwCqAJsc46BJ4rGUXbvkIU9w35aS3W+k1gLKX0VgWSLh3jyn5Qsm+owlQAke5Ny5gzq9Edg3jK+BGtnUSLSkjHty74+KkeUOk0REUOfEJNnFgmAAmxzjoEniqim3Us067mWLQQdO/v+FDDyUmwHkJrDsjOJM6Z4zgVLACR+MoXWII30cZ3sBb78BNJHhQn6CjrqRWsDPpL/AKf3h0gdb8N8kIAIBIACuAK0AmxzjoEnionFYK4Ol/5Q1LZIcIFrQ4iKL6F04L6i1hCaIK9nZeUDACR+MxVmb0Fgc6KtGOfPEd20pt7hhD9Q15reCmV4evln4qJ83j+0HoACbHOOgSeKyg5jkF4i0Mmghr9D4XVgCnYTttBMIxBsVXi2SAARjCEAJH4zekMMgRe36SnLd8Es7R8JxhYw7jLxkedgiyguwYoJFUJQDsO9gAgEgALcAsAIBIAC0ALECASAAswCyAJsc46BJ4o/SG9uuYV3N9N6HUnO7nm33qLF78O3+RRxP/bGvFz3SQAkfngT3TXWjnz29LHDHSl/SB2d2A+kW670V8WBdP7BbYzOnO1ujJ+AAmxzjoEnimSg7v1JlpCD0GC+WaUYPsP7Yox3oFBjfTa0cUmG+CAPACSV1poP8jLc+iwXgzsg6YTmXPWL/y5iOxhmYbrh2UlDRooRFcCK4IAIBIAC2ALUAmxzjoEnijjWY+Trie7FAcbeNICKG7wdfJ9XFvvP0Z3MfOU8ZRfEACSXCcwBuCHc2aOvS2eCGa9hhrz3gboXnwWNvrPk+89z0E3+COSnXoACbHOOgSeK0QH43EI9ZyrkymUC9uxaO91Nmeg4amSxxmdxLDQwC2MAJJcLPgfDF5tdJ3o+W4BX/1pXefPHlQgwAwxkjjXiBAQB3J3I0D93gAgEgALsAuAIBIAC6ALkAmxzjoEnipQtkDG4IWbHx1/ALEvzWTdduf5p6UxDdm8dzOvEHOb4ACSXC93Wv5luBtS7Sl91tv34YUvdttRs60fzAPoyhahg+iaF7gsVEIACbHOOgSeK3UjITbAZGpf3pxbYRvz3N1GiNpFUo5nFSjF+W45ro+MAJJcMj0CK+H9Frql8hwt7BkGBsc/qHa2ENWDoiE/p6bdU5+mVSE2ZgAgEgAL0AvACbHOOgSeKt0jRjl+gIg6rDMK1s9fpvfpmdd8IVnq1wNZyTeAQyUMAJJdXgrYDgHN9sTS0MkALYBqsVSEUk+fkP8fZTOm3ygSsp0jdc+LCgAJsc46BJ4rNSewJT5y0/c5ANIRRc5/8vCAg3SOzt7uJzAbFXpvDigAkl1fnYCMq/6l+gTFEhVg4fji37Dc5ONxaHjeQ8tW4i1DYdWNKxWCACASAA/gC/AgEgAN8AwAIBIADQAMECASAAyQDCAgEgAMYAwwIBIADFAMQAmxzjoEnipMCoclbBzAPHh/km/Ze6JXln2SUgSIOuR0b8xoaslEGACSXV+fpwtmGftQEEQrlJ91YLHDMlXu3YPM5mqaTGLbPldoxa37IMoACbHOOgSeKUpVdxdAgvrt2uUAPyHdfB2/bdRj92zTzo0LOLeB4ipwAJJdY05jBog8bhCOujDdyV+1OIsoSi0X/fH/x/ILvWyw9psyHJpk2gAgEgAMgAxwCbHOOgSeKY50z/BI4zPY9DKE9Sj3c3llTZxy7Ny99H+tN72H/KacAJJfrbgrUoaCSv022+221NQPybzHXUDZlVpdrF5nXgzu4yaVJhQqAgAJsc46BJ4ppKOrTY8dxqTlCPBVp2uV6Fv8wis1Zc6Lb5rH+20EyBAAknlIXYP+rU2EU8Khv2INUmJqDmkdkLUTuL4FK+old54A6EDRFl5yACASAAzQDKAgEgAMwAywCbHOOgSeK5ws0bjVPEIX+IIA8wQSfjF0GPGos9JHHOCdb/dbJcKAAJJ5TPdVIQhYlbr5dfRD0J031u6g7GKhqZlhVq9CASFpVJdeyeNsGgAJsc46BJ4q2qZNweg5Doiaq2k+j5BhLdeDbBnb+fpMXimh7hULiAwAkpxk26Jaizyf+Ne/k9zPUS5fYW+nV1E/UM0HXNNl+tsW6g+VTK9GACASAAzwDOAJsc46BJ4pgqK5Cmjzm+MpJTB8KWbSP/Ep0uk+pm1R6B+bR9lrFvQAkqAhZubufpokgvIDWDxDzYEHo6bi/1/kTF+JJX1xW1SOw2J5rO4CAAmxzjoEniu1R5cGDxa5bPHERxyvzW7yOCzhW//1LqEe33na7rcfvACSoGrXggFRZPftLrXCVKW5oE8dSTG1X30IMmHBTmqiZqsLU0nMsqIAIBIADYANECASAA1QDSAgEgANQA0wCbHOOgSeKJkgXsrOFRjDvzFObZkDmao+sRXI0z1wcreGh6KNtZjsAJKgwCup3aIwg7C4RqAg90KCM5zR6cfftvF2yVse07D5bs0+FLSN/gAJsc46BJ4plV1XM7l8a+zQ29M+Tu4K5OpdmnBoeNEwWeh7+wgIiSQAkqKCoMwF/89PyQOd0UFR48hhVSVAbx9HNYCG/lY6VP4ljZICJJHeACASAA1wDWAJsc46BJ4oHJ/u31lehNTL6rk6tMQQt0sreQ74rtytPzCyzNqmY3gAkqaCFghB1yRAVmfbatRLi2J3yl6ekJWAPnjVjkVAfviXF3y2WxtGAAmxzjoEniqgKnOJ6gZdFbbXPadypUQfNsNA2zsFZR5H81BnkKmw7ACSsDhjGpT612jYG9bSofHQYlCedTVB/fW6Wa2ELt7MSEeSKVn2FCoAIBIADcANkCASAA2wDaAJsc46BJ4qM383f3mo0EOKKzWGvQyUpqKvczERKVxRsgCCHmZ+d4gAkrV0paEhdsKY1V2rb6QpsfFhHbWilq8pWh+yFxHbOlV8GZC3bVBCAAmxzjoEniu8jlS3XL+b4iVP4fQcdlpsHaXRZx50RCzABmxIlX6c+ACSuFhzFYMQ5aIfnTMWsfdvyj5KaFsO0XBzm6oJOjmhnKJQnqTO0T4AIBIADeAN0AmxzjoEnilYaCSngbU6Nc2UrMOD5qAYt794P3UghpCFv8+Il0Tg+ACS8SHgGNv6n5WScrLe5//+xhZrBOGKvhOGPaKaaelk5ly18+6uB/YACbHOOgSeK+BoH+cLfJYUdQLBVR7KPNh5rqVe2dbBwsXIjx/zTAe8AJL0xfo+D2YrONUhSMCV3/WX8pVbWlLflu4WfZCCX3pjZOw9UaOYDgAgEgAO8A4AIBIADoAOECASAA5QDiAgEgAOQA4wCbHOOgSeKzAlqq8Klhtvu0zNaqc4Waj9Na7s2bQBrkm79oyguMGUAJL4RRizyepcijxP7dKjR1hXF3RcMx4SasaaVFQ6H3SnxQdvRPmGXgAJsc46BJ4rUIbaCOeCOCDi7J4F/DijdGKENeKJM+VNSSDOGiHEHtgAkvvdi98BnaDjrJ3BNNFl+FzAMszh3i5i2FEpeT/tA0ugxb6c4TCiACASAA5wDmAJsc46BJ4pD+tioRnFTEbgInmfCDx3IsIRavmsPaOQyL8JY0I6YkgAkw27pYxOUFK+OPOl+XPGEnkpwv8NjE1Io0zGECHb+ZJ7lJT2XxkqAAmxzjoEnikIO7vU7NISLY3fY4RKRgtunnpbBHEO/hRbRvONHW9cXACTE9KWhZSriwc33nxCGAMkPXLbPn71bF9SugH2vspAzf3g5Rz8lE4AIBIADsAOkCASAA6wDqAJsc46BJ4qnhWAZmqvlsos0YoKndBp0bm9godPurs4LKLLylQrTdQAkxPghbymK855OJVlBYrzH/L+vyQ2CMr3ayDSej5GewRZVL4JzmB+AAmxzjoEninDZsghL7P08qFr1U8WklPf17Q68C1h1+2plER5QBth/ACUmyQB8W8Lg24T/Xyfs3bS8kp7E8ju4QEZ+eYqeDDOfwSQxt41lh4AIBIADuAO0AmxzjoEnih1ZVPQLGpuSRBktubj/RQHwS/6Lj2mxJDTrtraao+NNACUo4g7jEe25lhg/31z900haknR1I1eb8lmUeWLGgmsZsTBz/KcLVIACbHOOgSeKi2SzGlaWEcJjJpn9TwiR3D23ueslbV3rjtodO5aHoDsAJSjlNnUhNWtCVP54NPgHg9L+o8jkdDiENDuOm5QhG8CrzCcS9LOQgAgEgAPcA8AIBIAD0APECASAA8wDyAJsc46BJ4pYcU/sY6ErcNe7zq510yuhYxcln8oUM88JpN/9VtFQfQAlKY8LwBpYgAJjTRV3/6FqZ8zkhEU57iWcbxb9bX/jY1jW35g7JxqAAmxzjoEnigTCdy+5Lmb95PFDH8YdMM1iM7YBRlMtMW1Qfkptcx8oACUplF0s50PFhehg8P6tr+Tkc78RhaCfwt+gnJdqWnuvfyg4bltp4oAIBIAD2APUAmxzjoEnit82M234Aakx7djt1fHGaAI5gueSAjgyCz8XrNhvkWTcACUqjvVjB2cauYacnjczeauBB+Kfzmczh4x+3EpIY7ILhcHuVg61DoACbHOOgSeKVOMwn6IqgbYGjDHfwcnQVc7r+t03jXiHyOM8bDRWkhYAJSrfAqVUkhaUIhchLishdlRD85065AOtWZgx42KpJ2tQMHH23MAugAgEgAPsA+AIBIAD6APkAmxzjoEnigQGQODDZAl5Gcx1BhG+odxPRc/jIJcaqUtUq0V06PlaACUq5l6AP9yTCMfDVFjdFGQrVo2OAbXsnDiQvV1bLxC7PDTmmt5bUoACbHOOgSeK7OAC6yyieBIccR3zAHU0YcbbsM3ls9reFcLi7k6Dzr4AJStsc0HQ+zUZtMNmirbEvAfRVGlfPuFL/JyMJozMU7CGaiwUM9IqgAgEgAP0A/ACbHOOgSeKGiNI24ct4yLV1NPuWQuDj3c+5tP91qBNcgP0zkeBFG4AJStvhaLbTjqMN3yMzNoocJCeA0k+pdCJr3OXqooaUr2Zmri2r4jwgAJsc46BJ4r71ofHSbfQ7yVDJMNc/11a+K1RIztQ22rfVH22cNEHNwAlLAdJpmQsu0DnM76PO92ai33w9aHoyyeE5QIpWcBjAPGEf82EDiGACASABHgD/AgEgAQ8BAAIBIAEIAQECASABBQECAgEgAQQBAwCbHOOgSeKZmSmaXuVWMN90sZsdkPbL9/iRq30KFh25TSnugdla+wAJSwSkd5EutrTO9AsJbX2PS4kQrrGTWvMPDB4DaiPqa1/b8eSYjLWgAJsc46BJ4qFqqcx0eQ6h9Ok5w3Gy+z2npnOVYnCXaSFtcOBfcVKLwAlLBZcEyQafrpSSiWkgar+GxaQiweX5lldP4hSvuFJR1DIp6twAnaACASABBwEGAJsc46BJ4oO0Ev4pAUUDKfpNpAn9srjqy25yTg8S6M+plhYOe1FWQAlLCCcX7JhmAIUrmVHDMFsBo0/wZZ3eTkIefBLOSQ/vrMOQYUXmNiAAmxzjoEnikvm278PammMwtuG9fKRf9xWmrRKQYq149KIjCKKVoEPACUsm3vGmqTiHD4YlqmeuuRCmplfNzsl3ccz4k48WDfPvE9InFff5IAIBIAEMAQkCASABCwEKAJsc46BJ4pbkFjV8uh37go9+nk8T6KyX8g61n3qXJi9uLOzOjVHcgAlLcJtWGvROhPcZVN22636r7MSkG5mC+lleSIPoiaQ7bIZPxL0IDeAAmxzjoEniusq7E4ygC4VR5QaqYRD9vAgLpPEnk/q5SDnfUM5xpSHACUtx8KKGgWdfttY5q6vExCoWGVf6UjZieeiuniGYvxmMCQSrcMzB4AIBIAEOAQ0AmxzjoEnipWQrIAkXlG3LQQNKL+vw/oojm8vAWiXCrZmAzxGQ1z/ACUueBSXwakiK8FJF8DeVtr2+NFOyjIg402UHAwTTcD9f5U6c1d7GIACbHOOgSeKUPJgBMTZvqxqScYUZm0l4JnTYmxFamakR4LWIlg44bcAJS78PQq2+NAK+pBdx/5Zm7uJ6NDIPOFZKpuCojPstZn5qP/onzlVgAgEgARcBEAIBIAEUARECASABEwESAJsc46BJ4r3CA/LJqK1o9Y1fu0yJ+GdWLS1sifKh6Mg/FhUKBL4YAAlLwMGa5XhJ50OPLGE7J7+iaDzVwfXUF6Mbaq1ckDjv5lQXyRccy+AAmxzjoEnihjBT5Lpi+qNJs3vfEW71PDO5Hl9MhOawXoIb9dXMlLqACUvLgOjWcBp9E5KkoqQnWyFKfUW5t+SIx8RLA0QcRFgRIf6gj0LrIAIBIAEWARUAmxzjoEniqwLEW3rSnzE2TplMTEuWJD76FCyLj+OKddb2zaiy97nACUvsal/olFQgKlk3O3ApKl/oW54po8ZPLWJz9psoDjnN2Ssjlofe4ACbHOOgSeKwNpS4AuBbgazsf7EXZ14MzItqjkTGz/75i3LxPUQEC4AJS+1VWXgd4Y19eZPvs72vAbDe72CbSdxRu+GR9RhanXzXnRwwN3pgAgEgARsBGAIBIAEaARkAmxzjoEnik4TaUzQmvjZ1mwKEKCy8tmJ2/5vaNuagBX1DasPVlU/ACU5MRuiYJHPoYDQcA1pU8hicJZZ1C2k0o1zMM1jv2HIYC+Cw7vM5YACbHOOgSeKPPwCO5hzDdEnhOfU6R5QX3x8aUIpuVnEkQGKwZHThycAJUTwmm4+TeBEEigSk6Leouv4cbefAqUi1LFSqNnxjDY9Qc9qV7lHgAgEgAR0BHACbHOOgSeKdgIZc9zi/OQ5bs18GegD4FFUOISFPYjPnRl/9ZuEF48AJVAxrP5F1WKU6l3KG2D+9bZ1jN5J52PftC6ZdzPHOpUKNm1vc01xgAJsc46BJ4pAd6RHkrxzQU1bQz/lgkkuAGHNucfEHY8G7BYQelj+3wAlVK+J2s0thZ+8h29QgfnLTx/nCEL+ZgcnmxIYnK39OtqoYnoOlWSACASABLgEfAgEgAScBIAIBIAEkASECASABIwEiAJsc46BJ4q2CejLhMehtNMs5mMnM+/YoEAsNvBLm/7DDiYGnHD7UAAltV2RcmwXaZPWXhmEIBR6Hzhqlvz8ZJ34r7ZA4osaG3KMM9MTeg2AAmxzjoEnihZxb1PGBiLYRUeBTYky0ry6ANTq+SREZ0f7aAQGG/lvACXCN0AhQVcifMgxKP2atJsCxjLfiOUvOHLpE7qUBNTnbfmeH1wtUIAIBIAEmASUAmxzjoEnipXRRI9S02cp4d3CrbT0EpQj1iRe0xwfG63vLZ8zofrzACXCOVvPgn5h0HzCAVjNJltANm5fcy/RX6W1q8/+5RI7ncZCprUnnYACbHOOgSeK4NJKJ6W56L6Vv9wT4ae10o6Xwk5Y0xDmVokYYEJZfXUAJcObiSQWVMgbt0PXhmQsSUjAMSR69x25VboxQnqDElxP52B6ExPdgAgEgASsBKAIBIAEqASkAmxzjoEniqulwdeTAXW64Dz02zIQK7lgZ/07SRYaFy1OsEWohpVKACXDm/abiMG87H1rpwugaRBk14h+ElsNgJSUGriiqOxKJfX8S1Ni94ACbHOOgSeKlaMv2ebOLG74cOZg5MWKsbrIJ7tjaVHcAyx61hTeNGUAJcOc8zS/HdbOJE19TyYPzAQ1OtnIEAoZYbAV+xr1SUGTZZXEMrC+gAgEgAS0BLACbHOOgSeKT6D3fDXrCu1YfTjBF0rAs8oU67M51IOrxperIbG2o0kAJciUADn48sn3Us0qYd3k1DFeBdE4OCGAHej0+/kC+p9vV9TEST0cgAJsc46BJ4ohHiyUNxkevFGbfJ68IPlGCp0BCrZOS/SaiSTMU7GYNAAlyNAKCsaUxemWgravms5MCE90OVuVq/8a4e0+aBGLO427b11H2E6ACASABNgEvAgEgATMBMAIBIAEyATEAmxzjoEniv2RywcwKJ1HPR9xywdiBDx5HYBoy38qJVCr9D23GMfvACXI7V+elRf86h4DeY72Ho7B1duosX85NFVckl/5SXc8/VnbUx1aqYACbHOOgSeKltNVgpgoFZyBi/xxKLCl/Pk+XAEC//5hMQUmaZx8qCMAJeLwf8RCiUTzXoBSJ7N4LAbXSmU1GY3OneUREMBmXuuYAK+LIhYUgAgEgATUBNACbHOOgSeKyRfKJ60Tg2VY0i4dCtg6RA2sNQ1NrLsZQb24xgTewNQAJeLxmkZdcR+74KVLNKr1z/T7yTN45lfoHmgWN0HfrhAYtzBUkYokgAJsc46BJ4rC5vtcXdJ3xloQjG57wydffyyMQ9Ae2y9uCLkA4VJ9fQAl4vKGwHDN5umajANfG7TYlzABBYMb0vTB6qbu60gFYbSdjkoYAMOACASABOgE3AgEgATkBOACbHOOgSeKx1GjfXYrG2vQdUh6i09hOBsJJASF82TfIig8ZkaUW4QAJeLymDS02u0thYBJonYTW4rJi7gscjS+LY7Duntg5W/q0qSPlO8egAJsc46BJ4rbSLZ8TpTHbtay6VoslVRxZ0CDiNYEdKk3UEbDU8zuGgAl4vX+Xvq2RVyFd+XqaWb7rOBHVPf5ZNJTnknDYsrzyhtc6zmflAuACASABPAE7AJsc46BJ4phsFRXWDP+lMr5XXSy0ZqgQJ7TunLRCJhrOYp7j03McgAl4vam8dH6sN8XB+OfnZ72stGQeICuRxarhKEfBQ9LbYQQ/Y+uwgWAAmxzjoEnio82+XEJhLQXG22uh3Ww7V9Gv2xW8e8qb1I0QJ0DFCdgACXi97SAZiHBesecUrL9sQCNZWTSmO0CmcVkyzZ8+cQ8vcoZSqrmMIAEBSAE+ASsSZ+AT/GfhE/wAmgBkD////////7nAAT8CAsgBcwFAAgFiAVQBQQIBIAFFAUICAdQBRAFDAJsc46BJ4qdZY34/AztfhaAenq4wqQJ/aIb0QhDIn2xAxaRePh1rQAMR7DCMmarwlDr1Bjxq/JbdRDnQuXrFuIuFoP4WroM6YOoy+WXAFOAAmxzjoEniupf9pR5QVvZUCFZ4UgUWAURLJ2V2dx3IttxSkXnF/HqAAxrG6y9yGbemrJe8AGIEZKZK57JB4u1sKAOYekDcp0TbZfwXoKACYAIBIAFNAUYCASABSgFHAgEgAUkBSACbHOOgSeK4BNmLDnE1uGGXxAC/DHSvYzhVH1bZJPj8U/3m1IZHwgADcYjHkDkQAOdW0fanVyYTd7yiP8OhTKSEgmQrwXNo6Vu/xxvrj8pgAJsc46BJ4qfgys52+GOTiX0vvwx8ORGAXzhwMWpd6Uu6hxP0q4engANzR+F1zRiuCEQjdx6pmpHMttgJzNqnNa1mFt36hIl6wHAFkWWpR+ACASABTAFLAJsc46BJ4pUzwZQwbRC7tVSnqi+0oBHfWioZvnTv/hNBF1dd9CxhAAN1H6EfTVWRO7zH+hIR5kbVglIFcpB1du3lJv70faDBz9NE0wgPXWAAmxzjoEnii85fZp8PfLv1UE2lPISAsJ9xdsmAvl3gC3TiGUKNYP6AA4dPjNpwv9y3UR8aBum6jt+PwIZo7DmsjWen4yxVmT9Sn4g9Pd+i4AIBIAFRAU4CASABUAFPAJsc46BJ4o4mEKpBAXTqztO5E+nJlXBKWqAB78exyJ3Val48Rs3vwAOH4gXsIKelJ1cMWOvKkRiZX/wBkMzxuMmVvDTq8Fl74wltwmOdiiAAmxzjoEniiUrhmTTn0oabovav038CLOH5vn2kNUeVfczRrOC/z+7AA4tFMeoPyffqzFKdCrVuT9iOtle8fecR/se3swE6AFL95oU63LDEoAIBIAFTAVIAmxzjoEnik98avyou35gQJByvH1oEeEVwy5JbTLwwvh9m0G6cEhrAA43tyYIFOD7uuVZ5w1v571Cfmi6Ey6SvxVwTzrKqSDRSu+YzToBd4ACbHOOgSeKFdQb39jHnGlP5ZiIcUOwmLSrg24VUuukgIYctPiS/tAADjjCQyej3CERKUHpgOTZsCbLyKpcLEhwlFKD7YCfbs7bI95vbWY5gAgEgAWQBVQIBIAFdAVYCASABWgFXAgEgAVkBWACbHOOgSeKrB/k6LnY6zfVSmNS7mKQJ3DirCuBjrPYl2+VdUx+Lu4ADjkngrKTH3lj+9FOPnwwL6vB+70dn5EPEprQ9AhRTHG7LMV7OOE4gAJsc46BJ4qrBaMtqMy9wp54P1j/2Hf3SBJMT4KL7BRDX6sDnP2O6QAOOwLAhmjdjYBbNK1iHiHQtuD7B2upErge8ZHFhXDrTACxtRzliqqACASABXAFbAJsc46BJ4rYx3GxFmci61LL8BfHvvpwDjZkYJLwfuzLsoT62WMeXgAOO8NdUwz+LaosRJyEeE1jKQYdePzCzBpTwInTOGiFIHwO9WyoeUeAAmxzjoEnikVz9cI1mUEpW3vPuTVn8inUzyFGMo9DeVgpGd7phmWvAA5DZfK9X6GtPsPxKSvqRbQxdNLB8YFSPp30cE7r+VrzEOgMaBqCbYAIBIAFhAV4CASABYAFfAJsc46BJ4riuXjVe19ZtMcS03dGa/Kf3r3IQuNQvTGGGlLiyyPb1AAOWcuas5zR1mW6QT1ZOkaGUKk+H7WThZZ3gKaHH1LYWTWzwWYvu3OAAmxzjoEninI3l0MjVBIUhrV6hKY6KefZ0o4kv8+Z7SM3op0sevZdAA5v7h+yUnfscOJZbbptGWIdyEbx9e2GFIWWRFoZTTZQ7ZdhOd7ugYAIBIAFjAWIAmxzjoEnikKrKu0UH3FVCKawdqgrPhb6NuwbssX0/yKz4jku06fEAA5+/F9YfhyjbkiFhPPYjRvR0gP/n94tNJqZiZRAwoYJ4ZV3z4ckQ4ACbHOOgSeKywpSsTCweu2JJF89mSKhH9j48B6qjlv3sWbDCxH4xc4ADn/J7CbbU60h3mWAFIkCEjxMQEHNjXAIdUDZ/RokRht5KLHRwoIPgAgEgAWwBZQIBIAFpAWYCASABaAFnAJsc46BJ4qLjJRmsP9XCdYbfX7CF1JEABI2/IbVNxgkl7riPI8sEQAOorQoKXPifKMS4uFWeAcHQ66YK92WUXMXHKzxUHhyed02PnKu3ImAAmxzjoEniiB8BJNOcWfqF0xujM6B91RMeGmNnJzNFJwaGxYnq72RAA6xztBJUPLS+8/+h6sKlbCV+EB8oN2hGBA3gThKlCAB8fOhDT9VW4AIBIAFrAWoAmxzjoEninZ9xO12Vfw1vZE8jZ/gS0gTkFX4ppfxow84igcR/pxtAA76gZBOJ295Qxu6aboHjsECndeWIGN3quZznuvVdWtQtwKaPmLO6YACbHOOgSeKz+Sgxv0SYv71//NIw1fcKp2q5XJibFy9Bv2vFSF30wkADyt6KlbvHPNWFcpESFVwB9fjqU/Y/Y4bMUd7UachmQ1ZZpICm1OkgAgEgAXABbQIBIAFvAW4AmxzjoEnigdtrOxvHS7mrUlRnrbJDRthHjPHRGFvaRqB69jf/IHUAA9gAQLYnYsPGn6SfJsRBXPv1HSRQevhdQCTcPbs+TsUn3Xp8elEBIACbHOOgSeKgbLtG6pMAdIuOtC/mgzmbRz0ZwwKOyf1oT7KmZYOjHYAD2RO3hVdgmwkDK6APLR9yQmFhSzWlQC3VGvXNZI4P7JqzFoRZ79TgAgEgAXIBcQCbHOOgSeKNlojvdPf/LgmA+qEeGOLHgsOLCFSoLfXdFSkKOFiKRIAD2ahtZPKARejgkjDxUEIo26UYlEypHJD1kZZaVTe0pNQ4tarfzS+gAJsc46BJ4ov4056E7BgYtXJeBTMdzfpU6XEX5XatQkp77WiLWyhYgAPcyzUfnVmHY/lShgsOw3mLksk6PolJhPcJswJwKiap9dk6Y/jNSWACASAB8wF0AgEgAbQBdQIBIAGVAXYCASABhgF3AgEgAX8BeAIBIAF8AXkCASABewF6AJsc46BJ4q6IjPVSI0pIkcUEtTfEBML2ZQC+Z2pajmY99mCL6LRDAAPc6H8xRfTzYB9cv4m1/UZ9DtyujHGOMLf5aOjkfPETITORg6XsZqAAmxzjoEnij2bhks2jsv7SCPAVDk9s42MAjKbCnNhF9j6bsdQFfvtAA98Tky4sJzs2BSUnWbs+VITKmgIqDEHjM0TT89iNNdvRyXrmUElMoAIBIAF+AX0AmxzjoEnirkAHgOUPJHwS86k6w1xiMhENgUwsVbwLcz0WJs2GqMfAA+3cb+m23hFyiDCxn7mUIvjxrgSl1f3EaFufcpQ99vglnWatFzWo4ACbHOOgSeK66YNhTPsE2QdtLbpi4uDAAa06AH2MEBVXAPSj9wQHIgAD9M19iwnKTndvAu5rMdAj02MwjzO0Fm6VsYBuXrTK4y1w58n9nmbgAgEgAYMBgAIBIAGCAYEAmxzjoEnirVgN3ZvBIw+oecjHDEB9dV609gEKj5UGcXxee7bVHF2AA/7FY9dFmw7UiD8ABKil+iPuLdeH24RobqrjD/gfkow7hWChYEXkIACbHOOgSeKf4larP/NokzmZLjit2R8aQFd8HtDyUodPOiNPe/kEPcAD/yHWymgPpdtDKB71nJgcZOxVySDQjjyXy04Y86sIQXPHS82kwbsgAgEgAYUBhACbHOOgSeK8TW+HhL195gyTb79IA/kYeoKwzr/ZMlPZWbODNOT5MEAEBaZUTemViDG9l8qNi4DGHQfB/OFf54eCkeAw1lfihpFgJRAUVIpgAJsc46BJ4rB2TO5NECmcFEX1aUWPDVgIQNyWtFdgFa4TM6WQBaBEwAQL3PPQjkUgp/TDTm/W36317ubgSRfzj9wTR270r2uHi+atFELZJSACASABjgGHAgEgAYsBiAIBIAGKAYkAmxzjoEniqasOMB4+k8dsl1Gfr4Z+NKEeif95TuOJPSV0GV5k7ZvABBOQjsra0CT2JxzR6oU6jHigYSQ98bweNqBug3LNKZNbBG4vFyT54ACbHOOgSeKDtmoJqz+2HM8vbmXILPk85vJyRBOejvgqRAEuXEriysAEHXPB396oOCcU5FbDl7vWPIPFIcE/GmUQ5nsIMtKRV1LNJXZMn09gAgEgAY0BjACbHOOgSeKyZF+0rhUlvkn7la2DtsnY4e2v9PNZRwlqN03OXFkBooAEHieEATLi1ZVna7UJR726ahB5pD1iF9PVP2hhhG5aX8NZQb3sW6YgAJsc46BJ4pdXxkGjX6/FZDA8T804ZNyCMa/4OAkrXUny5nzLxX+HgAQir6z2w2ZFCuruZCsOEfZ/Y9N7f+NaU2S8uzeGLhqbFN5tHdNmmuACASABkgGPAgEgAZEBkACbHOOgSeK1VgjDIhh2uC8xh4k85v4GzNB3cR/zwsYwLxBJAEgKi8AEJUZeu0YFTLmeMeeFpWo6cf6z728qUBczgupIcd4UiA3G1pEjWX1gAJsc46BJ4pktC/BGtVHAv4n7I0t6Az3SzyyQPQYMSjy2o5DjDuknwAQzQ4kcJAb8qC5OjiXcYI3hRczTP7wCan0hKV1QL+MZXpmxmpBRT+ACASABlAGTAJsc46BJ4rnXiwpRe63SeNFrIkTGyIF/QKlBbGkUCOywf9IszoLBwAQ0S+HrW20cjY8qyFwNYgr7NRlHKfCOIk1ST4EOIXCZuY7HiXNGniAAmxzjoEniuQAcLNYUZKqSt2lfRUqa7ci+SEucIiLAk8pnuZx9HHaABD5LCXI2V82z/2/2k155wT28k2Z/M7D4RVLd8HLIK5I9DoUkjNYb4AIBIAGlAZYCASABngGXAgEgAZsBmAIBIAGaAZkAmxzjoEnikRMxtJ4aHNUNe9ipv0EBKcQ4HlhomTms81nzjYiFDuSABED2p4/CWOVKo8azuwPZ9nNd3fBvaZuty5Uf2NDGC4wNRQyDtAuxYACbHOOgSeKKyURnTFzH7PhH8H4qLdplSSDxCSvBXQSzoFBXlwak5QAESWET7zPBqLUs69N70rXlOzRyROxn1ySk2OgvSwloTPDyNulQA2PgAgEgAZ0BnACbHOOgSeKggjIrFCIV4jrHbhTU8Bqh60urh75XE1xtFHaaFQuuCoAESZOLzU/Bq8aPvwje4NXzD3ydqB9jHEdPuz63FBrIAD/LM9JoZoYgAJsc46BJ4rz+9mTHVwYyrKLYXBwt6aHL/fGet1c1MfP2GJ3EIa0YgARJxXWRuOt6jnLXr8ta+aG8A9wgOipdPwXMFUDDOXUqB7t4DiZuHOACASABogGfAgEgAaEBoACbHOOgSeKbPaRvJLB+IOxEFx3H29h/XTRbrH3fXaF6xcKKaz01uQAES/5MbY+6x2yeDs8rVq+kXbb8WLDzlApHNtVtiCdnSmMPpUHK7X7gAJsc46BJ4pT4E7jAuAJTjAGU0YwhUeu9lBudLl3xiSV3MzNN1YNRQARRJP08FshET+0WsbO9g41TvH5TOMLAAJ8oZwm4JwOoZa2Pqj53GOACASABpAGjAJsc46BJ4oL4hRU9iU3VpVU8574md9nCYYVUlg7QHnTL8+SpAGfqQARhbpNvf2lpV1uuIEZkIKbC0tUsZ4AZrDuObYQ9M+2QE0e23mvHdSAAmxzjoEniooQ157Q6istu2J4HGFIutvtoXqn+Elo9/BVYn2GW3tvABHMjAMsvFq0D2/etqSYjMVX759tXxhQF03dUAUlTxn/McQG5ZtgwIAIBIAGtAaYCASABqgGnAgEgAakBqACbHOOgSeK8rFQsJRfZbYvdqjeo5oBolJHEeSbc2/uTkM7tnj4hK4AEgg0jQ1CttrGfn2fqwpoKQQPbcYARzXF38eCeruayXnlrB6i9n6mgAJsc46BJ4p62UI9aq/B/d79a+ELiOJ9fAyItjznWCpo2W9DUwXlAQASMVyaKL0SpUSHWtHoLjp7n8AmFD3jrkoutMXqBSKD1Dq2BVlkBi2ACASABrAGrAJsc46BJ4oOPJrABehdrzFq96T3fbQVbSMZJ9YhsXaJhkk2Rjl+0QASNdcpkzX/daHphL94T9IipjaXMkbmKiG/zbIwWQx5tPcgG4VMhmiAAmxzjoEnij/VIcHu+S/sJ7dKZBvTuAksjcZ7eOP54OXmb2KQZHz3ABI94CqYLO9TaOZX4N6PaARGTxmBW6rb7QcpoRNN/3lZFLtrlv3JoYAIBIAGxAa4CASABsAGvAJsc46BJ4omNMUOhA8cp9+Xg//mKGcHH0mtxi8UCBqAd7as7CJpbgASUjnW2btqggmJB6arA9PdbtPawQ0bmQSNOlCgLaXN9zzQaI2N7l2AAmxzjoEnim+9po6sNIxPlhmmP7pFnd7mcCTZfJ3JVGHmui/oe1dGABJ6S+2ROZpYgc65PbFHIl8TqB3wcPbYExJz2J9dUKZzA3HcZjQ9H4AIBIAGzAbIAmxzjoEnimoYyDjCe+CM8tgfOqCjbsb1VDD+VB7O2Ri9Aj0erKqWABKBRNR2k/GleMYWgzMp7tBT6hZkOGHlB7NcEGZWdRz6BPsppW5QUYACbHOOgSeKaPcw2t+UiFXkgq8NW3L+ukidJyIARhbkB9zb02Kq/lwAEoyY8iZC3GMQlJ5NV8c9MigHGo6y/EOAZGyDpbRvwMKASkSnVYwngAgEgAdQBtQIBIAHFAbYCASABvgG3AgEgAbsBuAIBIAG6AbkAmxzjoEnil1kS2T2zeBIuWb6Gl1geQ0RHb0+xK9DVLara1IaiG1TABKUI56qIlxlnPaA322Pp1n2awpEXpaJ8kzzwzQRFcfxaUqFmE+T74ACbHOOgSeK7bnXWK4HKH3s9baXZVVW59WtRYoqhrDu6m2Dj7wJoscAEqG4KBEfU1Nf+jSBZO2JiOQ+o6RnRUQ+IDtCv7iDH5Kmc6jJpV9bgAgEgAb0BvACbHOOgSeKcuDmedXveuYJNRErZVlO6IGWGD+K07i8nyYtZlIJYLMAErPRJkGRMoexMcCUylm3Q7pluLsycCtkfnZwsW8YMU4G9tpDZ4hegAJsc46BJ4pwgjR04mFVGuO7s3KlJDP+D/UEof1RR+hdtIwtECNtZQASxd198TKh7ey13oRteCuJb/nutIGfNA0XDK4wzF+LUyn5qbpiWB6ACASABwgG/AgEgAcEBwACbHOOgSeK0g7WIP9jhv3F3ivE9r5F5O77NBx3eGufpn6NORdtsTsAEt/lC2bW2OtlMmfqSPrr1yOHSVinwTTxtI6qabjYON9wAtPl5ZvBgAJsc46BJ4r5KzvkclIMxlFJUHClDukEQkUKY/Dneojm2xHnwI1urgAS7FouhqYcRSjtVx4PPFJ0otQDclOywtsTvfw57p1UofDlKhBJFY+ACASABxAHDAJsc46BJ4p5CP+HhzOzEIcL/b8zkEotRNmoF/X4+YpLzNhlI225TgATeIyWaLnciOIOFrbQ2uGETDaKOHsaUrW52mjdA2pKybn3UoNTGPSAAmxzjoEninXrPKoeWPgRw07n+CYjsEhVfq9MGPIpFiLYrYlMHnZUABOV6sTw3g9MW4Ui+3byTuKDCj5SSsIZprRnL1BtTGmu0jgQbFemg4AIBIAHNAcYCASABygHHAgEgAckByACbHOOgSeK9nprTy22dAYgEQANwrX+LnnauR7cV7a1NaXROkj9TeYAE50vOOpK6SYr4w/hKFcUW9OV31LQsSwnJu8pAzm4/BI02D+OesHbgAJsc46BJ4rksK9lRaqWboct5bypM7cVK2kjXRIWJ+q1bpst1KHQZwATsZMDpa4vtDS9OwlLIb6scp4tugyY+IJL0SViDAXPnWWjfsSZMK2ACASABzAHLAJsc46BJ4q6TorzoTbxM2NjHxagxPCjz9Xo6Ce1kVMoyaI6TgO4YwAT/YYc04ECJU5XFQJ3bybqjnvvvxYfBZyVlvCOT/o/pGLRytgqeeaAAmxzjoEnitru9vNkqbucCsusi2MWiuAGfSARJ6aBKzD63Z43Ca0ZABQmhD2IGQe/yybc+rNTqky0nFcWnPavkLaDT5RPbJJRLkuzs3yR5YAIBIAHRAc4CASAB0AHPAJsc46BJ4qIVm6IqiafNH9uX7qc2ia0p1glIty2m2GQVa1rjJ+f6AAUZXc033Ko1BGD5haymj7hlolY4SyIEjhVmATabcEq8vbUHJt574uAAmxzjoEniofwFVBXdlgLATMhAel4wGaNfFakJd1OLJXMKfPrIpemABRnfRZ6YfzjARmi+FQrUcaAnzsTgDH7T99GWdE+1cMJ+cv+RA7u7oAIBIAHTAdIAmxzjoEniiE+zy7T7LczH3yzSgD59/EnGRNBqIUeKiNsS1Mvb5kVABUcAtI9zppzN+8KBrXsVXwxQZmUvSVXaDPMCxtjxsbceX5zUQh0VIACbHOOgSeKKa++lIiVLsxUbletO1qvXpvo9bH2V33oxr1q7VC7Z60AFUm9Jvl3hMqi629WFu8p6y7ZGCkNm+ZbFFJN1Nm2qEvPVFAkP6kugAgEgAeQB1QIBIAHdAdYCASAB2gHXAgEgAdkB2ACbHOOgSeK7RZ+6TCOIjSdy1HpebLeghpPC0VPgb3n/UkTnaFdV8kAFU/7iDZ89R8cQvhcEPyWT5ZBcUvDerUMQ+ptPeJ7XK+A5Ztr7J+CgAJsc46BJ4q6s3EpX9g+FY1lh8NV3mFvkX7eiTPd11rJ+sB6l/e5ogAVdGjnGhEAFSIO2Okoy+CMAr23EzlMmkTMzvh15ZVGZUAPgCT5WIOACASAB3AHbAJsc46BJ4oXsEZ1oZ3/k0qUAlUCNGL1qZM/ENyPouSgi8jjWGRbLAAXJGSuC5dAhxa0Este4P18IGF141Bl4YQ9va+sRPTRNrx1auMoZViAAmxzjoEnivqa51vBVgbExDmVnPm4jAPcV4fOXgwsLsha/ZIgEjkZABjIMmkwj2Vf/JYddDzoVBhnKd1F4YfCgjxz0YS/9OxP2Bh+YoOIvIAIBIAHhAd4CASAB4AHfAJsc46BJ4qpFES8eMWju7wfF6xQuzYwm7l9O9zVSCXruFxIFsRSYAAkA1IuDi/yTZqedv7k4e4C79rew9f60PwODdHx4u/VPrbtxfX5SjGAAmxzjoEniglFLgx/da4GYS73bc/nRDfBKI/mhM5ENG69d6aYOGpxACQFpl98Wt+YmMMRY70NugjArByxE6s26i5eL/xU9yYjjA0xp9nbW4AIBIAHjAeIAmxzjoEniniBwL0DSIeu6a+p86IkAbw+8zdqVIEUX//3FustrdQ9ACQFpsMLxZDLNQPZimDuTtRec5az3BrCwkAgWYrWCndjqxolOy6CjIACbHOOgSeKhZUie7AOJXLhlfF1mmDuD3oYqT376eUdCNh9nWd9pRwAJAXqNOlVL0AHgY1HPDvlVWteS28i6nXaUBboPeDlXtQ3LmElcdb+gAgEgAewB5QIBIAHpAeYCASAB6AHnAJsc46BJ4pXq5ovRj0SOxboTG12v/8adhKG/xfozYATBlwCwKQDowAkBi0vaxwYa+rXkTXk2hTY/dtfj+Fwm52QijSulhtkzMiLXojLbDuAAmxzjoEnisvGcUMMHLLAT0fqP8mpcYf0Ik8kxrUxCrdNwX6eOOzoACQc8N0+bf3Ak8yqkPNhX3YqQ/UmsvJ5Ivhiy6SNz9XLCcIkky/qp4AIBIAHrAeoAmxzjoEnihyQJLUV+OYq/dA0gCFmtL3tJc9TVV//n65SaQyburY7ACQeKVokNP/6m6+GJuJkVGt1Nv2xiDK7eLG3jkGYnyZT4WG8mtFkg4ACbHOOgSeKp5y7FHyNb48FOawNrJ6YruVliZSE9GscjSDK+7WiSVEAJB5wO2JDqiRS0roaseIxydRgwsw+gqkNs/J5f7Z6wHRzlux1ttD0gAgEgAfAB7QIBIAHvAe4AmxzjoEniiNngPahpMhr8dKQpY6oftkzUn4vP43L26g2iVur728dACQecWcQvoI43ufzxjDY4m2nAAe4WxTegeNNhv+jc3WiSRGfxlsTjYACbHOOgSeKIMzsJmDh7DZIzxM+YshiJnHuPXgWeTD1gRDx4xxgPLQAJB5y9YUAPjz8VwLfFzct4lQ8AJuwfTjDkn0MqnOyJijEFxdD5U5jgAgEgAfIB8QCbHOOgSeKQwDMwe6Ri7VQj3qput6ZIDcMg0bQahzoiJ23wOcGvQoAJB67qAvnYjHNrS0/9EsWEVz4s8leX1N0ZXK+7rFK/lAJPeVZsvbmgAJsc46BJ4ofnRa1iA86YkyHcQ7Ctp7g13pQpO2ueOl1VgmvUSG7SwAkHryx5DOEkJlK3s2NuQiguYFYZrQA0IhmpUsTsED5y5R6DRS5ExGACASACMwH0AgEgAhQB9QIBIAIFAfYCASAB/gH3AgEgAfsB+AIBIAH6AfkAmxzjoEnit1RXtm8OMwRU/748r7Dhq4aVfKctrAyeDL5IrRtb9YvACQevLJqPJ+b6YgagnsWAVedOyiH3aBsKE1Fe9RLbtUDNpUXgIxOl4ACbHOOgSeK4tYI8MJwW9032D07Vg7Mrqn1t+6fHumVmrmp0tAODY8AJB69FcmxLLBCY1JVW116SpC+AVQnbCjq1PdJjEFVgWw67MFLx0TagAgEgAf0B/ACbHOOgSeKPMUPV2EIvzpKsqGpjEMfK7ioRZwivvSeqoB5OOrKUikAJB8GuBcl/lVg790RLGwZZqXF3OieUAt+eWTxlQ/jqGZ4aVBA/0nkgAJsc46BJ4p5nYzhaJmcH9vJQYbRkGApRMib/tq7GakRlp4l5k065AAkIMJEUBq23U7IKVhoXzafvfFY1ILqwoqiz6KDRCBP4sjjMYZXe/CACASACAgH/AgEgAgECAACbHOOgSeK9CW7KGadqCRjCKjMQ0HGc+1LvureUlRkzmMzfdknr84AJC1aNIkecQEmiOYKP2FBclCvffN4XTMEFqQbz5S+8Dn2XTlSAMPXgAJsc46BJ4o5DuD2ps96sNcpeKrn81bjUiExWceEaeBJgRyrSVwMKQAkLdjzGBV4W/tebS7VRXnZZuSHKyzpZhqhdBUtArYau5n9nvIbdzmACASACBAIDAJsc46BJ4p+mGyYjY1JCP6b3XnoKMKnXhd+Wqc3lcc3O+Hr4Vnz9QAkLtiWTlnS72DbqQPA6OUsatlYC1WamQtYIYOWyYqVQalGZe3a5EeAAmxzjoEnimqQAdRYbghjpj9rk3Fa99YGM8j2/cw8Dx3PUPK9Iu35ACQva1aC11/mBNbUjVere8+v/yxTUmjCp0sMJXwZk8g4qF0S1YTXrYAIBIAINAgYCASACCgIHAgEgAgkCCACbHOOgSeK0lYoES8GeFM926WFpDyu3f1wsnijyLQNfA+TK8CtHcIAJDBPBnUTRjH5Ddt5JlMd6xAjbstWyEmPNGFha4IJ4P7pSdm69uaqgAJsc46BJ4qHnY6G6v6W27hLxqY82VGa/XTwo31UFvfHORcZLshD7QAkMt6PfVTFFhfIejpJXDKfYPyDoO5E4lryOcVRofmrqPpbxOs2c/+ACASACDAILAJsc46BJ4r5DNg7Fe4EVRo7IaBj7vEiJCk41Soj4EIl3nF25vxSMQAkNDfW5Zd/MvrM5WSYIJSs75RFNoVdDK3PCo4zG1ft949DNY03eVyAAmxzjoEnihq3DzRjX+IEoimFWoPk/vrBNMLcLPQ4n6xDotoZ2TfFACQ1qE0mXDjfuCRsjwZ5rEqVIY37S8kxKWcn5jlE1u4UpBPBQfW6gIAIBIAIRAg4CASACEAIPAJsc46BJ4ocXGe1vudHxMstO4BokV4FW96/TzMkfx4u3GRHiuoOhAAkOLNghoWo811i5KRYUZWSfzLFZWNPINurD2lwhyGAg8URykXAmO6AAmxzjoEnimwybRlI4flHwHyMoQl/4fZUYRd7ZO9GebvvH0pJ3FHDACRDxPvl9aRYmKQT0yCBMs3BLR0iRugE3K3lgJxNUXjF2yB1KKcI9IAIBIAITAhIAmxzjoEnihwUHJDFqbBuvprHLC2zTorue7+EFRSiVBDJbXDBBCPPACRD00v6N4WO+Pveca6y5JK2KvhKtvKn/vON8uDF0lkaZQ6xI3XTUIACbHOOgSeKWUXyYTuW9m+BwUob/QubfaqLQTT1Ojdb/b3lWTJs3G0AJESeLLyoE5cT611vcyukEVs7D0/DaPw4xCs9j94WrjJLnO90zZIWgAgEgAiQCFQIBIAIdAhYCASACGgIXAgEgAhkCGACbHOOgSeKiu/eAi9oJQq0e0CiLABlg+V6bVo+wAxXuDDnfUkyQUYAJEV/082ukYVaHzJzpTivk0EIqQX/b0+jzPIMPtsTZHzO3Y4JdNxggAJsc46BJ4pBZJy3c8zIVX+Ltg5QbfZk4g0752d/Ya3JsmozcPE8twAkSCcptFtNoB4cBsuLcLILaB6SWk8e1D8G3iz7MltKiFmp+GT/q2yACASACHAIbAJsc46BJ4oqXsBd1H8Xc9TRZaGZWy9fTv06T8wlYIheg36DlVXfUQAkSwHDJwJDRkgkzUhEAt89Ja6rlN0dEPnDqtoo1AXio2cLPEtwz1OAAmxzjoEnimrasF6kgdfF3gWkl4MmRb20MlftmOPw+pwq0oMtnZpUACRLBahiXeBsdsdedSEqPwpSADuJRjMzR1qnlmlfZuH8Ky3cLsI8poAIBIAIhAh4CASACIAIfAJsc46BJ4qAbBur8R+7eoPxLufD4ALwuz1qcQ9fnZ91Bt32qzweHAAkqMwul7TnJd/FUDDP/JzVMwOOpB3/yI6Umo0KxPPb/2SlRSj8lLuAAmxzjoEnioEUD2J6+Fz75aHKSz/Vmw7WvtLmB+wHuBGcDw6JBGDJACSp0urpORVlFW6LAKRDpFnLilNqUXxvNQBfBFn6M2vyIxbFM8D04IAIBIAIjAiIAmxzjoEnisGXQ/CbnwAsaV0Z8TYcqGJIfUcQTiI5CUxwv4o3lMovACSp3OaSaxx1MyZyaqpmV9c2Hf5K1lYz1WJFnz1vkklmp4047W4D4oACbHOOgSeKNkAIAmtqWYBQk7RbqLLWkow1My+iVrspSYYVtTGx33EAJKoPCHGzit31VRDMqR/3EF1W4VeILeb7NKRNYn6bNmRbel6QTYejgAgEgAiwCJQIBIAIpAiYCASACKAInAJsc46BJ4o23dOSlEKVvVLSV4Ok9RPQT/k6RvRVPlqOwdps1TLWLwAkqo7nRGRzFdBPibE8PREDJN1M/HZqLbEP/2DMwmLAG2Xw4A48EM+AAmxzjoEniuG//1vrPx5eLHAr7Om1DlbZ8KXM8J03nop6c3PxVweHACSsDV23dzivuwhLr2YiL0xYwJtyPrBUIKSfuxRfA2UOT/PGpFVH7YAIBIAIrAioAmxzjoEniiaUcfOOk+h/X1PTYoM4AVERtX12tLNFlyZfXIBQnJ71ACSsSELGMPo2G+5jNyo0XlKWhN8IJYZpFOPsgDIStY1N+bO7eBaa/4ACbHOOgSeK9rD9k4xh0PGJBc5AgWcpAeBac1t9DORQtaH1DRhFv0AAJKxODDx6F0US68stMVsAjVBrXNV4KlcVzJQWefccWypvBTdbY/UxgAgEgAjACLQIBIAIvAi4AmxzjoEnikm2v/70j3fQ9rGrX/sN1nk8fcxm8ZOquuhHfrHXaOtbACSsTpc4xgnecBX/cfYGHx3cxMiJXsoIaPSk2PuDvmKo3x6c2d3G/oACbHOOgSeKqLkbYUnAxvOMjXvFw++a2pFrNBMmV/Qf0Sd/E/wioIkAJKy0CaYpdIUfAGefrOADUh4Pw1769QheqkMwc0y2Xy8rScoeCih6gAgEgAjICMQCbHOOgSeKlh5cdaPNFbSLakZBgjf8eH713J/5LO3Ff+Kdjm77fTwAJK09NmM9fSVaOzRpxmQg3NJ7yRpdbtwhiyOHfBO2WfQ1BmdSZvdvgAJsc46BJ4qlqTcTIVm7xFz82vje7zajf9dG+Vy8YowVWg6/fUMbTgAkrYVBnO93uqLKgMJko0DfB8e8mTS/3zX6Jvt/T/+hp9PNelJoerCACASACUwI0AgEgAkQCNQIBIAI9AjYCASACOgI3AgEgAjkCOACbHOOgSeKqqTomsKF57aBg0npmiAUpV30mCxRuPvGzxaIqbJzeSIAJK2klrrcXKNgskv6HzRNHDp8OpzHocpWRb6obJyC0OHtK+XP1e3RgAJsc46BJ4pBKmCbTxzoX8Q13M/4BfLc4KoEbMJnlNwMIXz6KDvLDwAkrexVoCYPej/HsQcS5aM1tpcXmTPScehQiHKIb0cTeARnfEbuhxSACASACPAI7AJsc46BJ4oP4ehBYdQnveV7uJQRcEsBrCA74UMMq15q5V56dnh3/AAkrn6q1gP7OH4UOdiNpj43skyZLtIFqgICgrHXBeVVO9bpsPjuffuAAmxzjoEniuvN7v9xLL2iTaMUuZMsL72uqEFb8hzh+W8LfGEMtWM1ACSuhX7FsO6ORR7QSYP16LZzI5If+iLKOy/U5vG5gUD62AGPYVICzoAIBIAJBAj4CASACQAI/AJsc46BJ4qZNljcg0+zVNYWKE657q/7xZB/P8h4/ECcZZnzM3M2fAAkrqoxw7NcwtFLWw/PCV/4i+Wd3FAWBMdxQtYJ3dLZ1r2TZc/caiqAAmxzjoEniv3f3a+V9AzqysmveWsL4QhZLF9cx31vEtMyz+o14Yf1ACSurgjqnbz/zw9vWCIHcGrZIi9QY1PkurMnA1KBzY18pNUkvj2h7oAIBIAJDAkIAmxzjoEniigcHzb3yMYEr5bRAPR4UlqY4thW6oD2L+Zoqf1/e7c6ACSuuGGIYRtEVC69mDm58pkpbf7vjmM6mr6vxgWs5Gz52hYnQovEEYACbHOOgSeKzp+F1GalORj+JB/EV7o+BBvX1w5YZIG0nsIT/i0WR8QAJK7AqzYy+D36AaM9JPtox7LSA8cCuTllbP5/uTthMcnfJXs1zjeZgAgEgAkwCRQIBIAJJAkYCASACSAJHAJsc46BJ4rP9HyeSiAZiRgDiNHeQ/nFzUJ0aU4eIE1K6fZt5yF1EwAkrsUCFokKCRl+Wf2zGsk403PE0iCAVwxX6oefrWNIpzbi/mbqOJWAAmxzjoEniotC2Ui2mUJGMnIemCTnTfJwmm3ILRSlG3gHx5WwEABVACSuxcPjTUgNpy6jD5GFu077mwORBhAYxnHqCosfLs6d8Uqc51oRcoAIBIAJLAkoAmxzjoEnilZYFqTvzFq5qMnyOCekTWiPSbRjb0LOD5XZeqzwRMYSACS2gbckULAjupc5AudX+KSsA7TWAI9ZsbijJwa0R2sy4X2mVs8BAYACbHOOgSeK7EEFvB7TWUS/bhalr7+ERhZLu6NoIYK0ZrDZf3iquLsAJLaC2c+eLufWhk6/knbOCYSvyX2seg0V250VIo5ZOQtxasLcd9zmgAgEgAlACTQIBIAJPAk4AmxzjoEnijaDkoXokozsYPXbYgJ5ddJEIAYlOfLRnLKt25+TN2AuACS6P4dGP7L05PiWWKwyYCwEIZalc1K7PYM3sU8V+ZA9jBOlKDF9a4ACbHOOgSeK44FxdlWwnMNhPZ4beOTBxfIo+2jw6toWYVPjbtFMywsAJMBZSuqqhCXV4/0X6IxdcEOmWQb/sJDbTv2SqhNtr980pfItGssYgAgEgAlICUQCbHOOgSeKwzbuIjkCdO6nfNGu1KYWpzUqcb31MYUU8lUa2XUYTloAJMCWSYNwF+SLKoyyzJH/KucwB4FUIMeZ17uNEw6bsl0aZyvuJ0WXgAJsc46BJ4rkAhlgKq4y39sk77JyKKdy3UPGIUEFWhP6EnO5HvunuwAk1bBllJHlshzdcY68n3lWCNIYgc9lk4kw4jWNIiqmX5jiZBuImQGACASACYwJUAgEgAlwCVQIBIAJZAlYCASACWAJXAJsc46BJ4oe6thAU/BlM6g1U4TjtTUiIk4+Y3gVkSDvN6uDm3k2zAAk1xJGlzQDcbxb1JRXyMDaFA3WVdiNlS/LNhy95aVNXBetEW64SV+AAmxzjoEnik0/SMLZUHuk+LYmdsVNZIoeyusackgZ8X/CBLoneYHtACTXEkaXNAO2jaD55I7CBVetrTld23r7IUjlAie3He6+KJ9LMUCl5IAIBIAJbAloAmxzjoEnimcwkfO7FD0RMDqv/gsI8FPLp4YBPBFubzLj6y7NGlrgACTXEkaXNAP5wd2tOh407gr08Ekmlh0JRmp1zf0s8L8qXOAisIXUrYACbHOOgSeK7j1r5Igxc7WDepnaBia7yl6rsxw475XNyucNUuSNXXkAJNcSRpc0A64JXzoFt5OtcUyQIo/acG4dMgCm8Q5bHi55eYLCrZA5gAgEgAmACXQIBIAJfAl4AmxzjoEniiFbsJ/EItbkj1Vbr/so30Tekl3j6D1kt/MEBgs3NUwSACTXEkaXNAOe53rM3M1AIyQ95K4Gv1ufMgPPyvg83XrKZ1r+8LNh2YACbHOOgSeKlWytAl9EJavTMk44G/oDIIRifxJVlGa6rhjeF+X8kkgAJNcSRpc0A5JQfO+IFGBUSDUwBWjbFTTEpngNyNrHCGQbb2RezoR1gAgEgAmICYQCbHOOgSeKGlBf+FpYX8/IGLUs/Ma0TCjo3FpgQl11AkOkXh5rCewAJNcSRpc0AyBbPYd/uxLFP0ZJb4AyO/VM29sbvxCXADAsLcoodPuegAJsc46BJ4oH3+Bs+SB7g+GCj6WQUMrNeff+EtREG4obJHZiQeZTvwAk1xJGlzQDSmvKEFBOrekcqubqFWQADBR7deWQ13LIo3aKla4jYjqACASACawJkAgEgAmgCZQIBIAJnAmYAmxzjoEnik4GGDzPIfddDZXRRsuxEHghuHMGDGyWqFNtMNjaAXn2ACTXEkaXNAMZKtV/qW8F2mDHwHtHFAPYH0w3LKnZVAdelF1bRJYuNIACbHOOgSeKKj/oQJQMFYvxww6Fj6T0IBZPau++u9czj/BuF8GP9xoAJNcSRpc0A0/XjI+6Ab/E45U78EOwZL8QRjKVyXN8hQNFAt9DDPGOgAgEgAmoCaQCbHOOgSeKwGoO+ssWNuypKxTtUvpaYylrXNVpo0cvow4FwpXZae0AJNcSRpc0AxhaQAjeAdwiIwwEyy/oqDdpENFm6Al5Y59RMEBmw2Y7gAJsc46BJ4rNy86mSs3n7U/Yo6VuDMUs6Hh8HAdrP/GCM6lqGUpGgAAk1xJGlzQD3ZG1aXCeCIrkbkb2Ztwe4vFZWoTEqBaxNt5DNFzIXXmACASACbwJsAgEgAm4CbQCbHOOgSeKCt1lhbW+KBhibhzyGim2nM0IERuDxMHtqiTHoN+zlt4AJNcSRpc0A63M7dO7Wzw44Il71KG3XIVGlA9MH7C0Nry0+al9gCpvgAJsc46BJ4ozA9/gDK/Dxgw72tTjh3qkRN+CtvpnjmiTYY8hoxa9NAAk1xJGlzQDKGUR08KgINEddojdXdgwENC328qlYVSHva5Y9c75JNSACASACcQJwAJsc46BJ4o7dzdbcPNqAVUHLh4jf3r8j6UwliFGY8OQRCBWTwdzUAAk1xJGlzQDCVle2fDq2QCAioQ2w4j/4kX9/FzSKUnesC3hIRXD0IqAAmxzjoEnilC/+zivz9V16et/1IGF0pTb6kBHaE3JlprZBVkSpMGHACTXEkaXNAN7ognF9HyampgLyvNtArRuT/0kT8rIcRkG8ltDNmNuY4AIBIAKjAnMCASACkQJ0AgEgAowCdQIBIAKHAnYBAVgCdwEBwAJ4AgEgAn4CeQIBIAJ7AnoAQr+v1aFECaihKWhhFPwJJSX93VCPHqVtG2SaOmldOlsYjAIBSAJ9AnwAQb8mZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZgBBvyIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiAgEgAoACfwBCv7d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3AgEgAoQCgQIBWAKDAoIAQb7iMO7sAbprOGkB6d8EXL0CJqstVVeDkuaSkMTBgc6wzABBvtmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmcAgFiAoYChQBBvo9kxq+/890Q2LpnB3kKyWcNVA83qUSLAze6pqWpKsrIAAPfUAIBIAKKAogBASACiQA+1wEDAAAH0AAAPoAAAAADAAAACAAAAAQAIAAAACAAAAEBIAKLACTCAQAAAPoAAAD6AAAD6AAAAAsCAUgCjwKNAQEgAo4AQuoAAAAAAAehIAAAAAAB9AAAAAAAAMNQAAAAAYAAVVVVVQEBIAKQAELqAAAAAACYloAAAAAAJxAAAAAAAA9CQAAAAAGAAFVVVVUCASACmwKSAgEgApYCkwIBIAKUApQBASAClQBQXcMAAgAAAAgAAAAQAADDAA27oAAST4AAHoSAwwAAA+gAABOIAAAnEAIBIAKZApcBASACmACU0QAAAAAAAAPoAAAAAAAPQkDeAAAAAAPoAAAAAAAAAA9CQAAAAAAAD0JAAAAAAAAAJxAAAAAAAJiWgAAAAAAF9eEAAAAAADuaygABASACmgCU0QAAAAAAAAPoAAAAAACYloDeAAAAACcQAAAAAAAAAA9CQAAAAAAF9eEAAAAAAAAAJxAAAAAAAKfYwAAAAAAF9eEAAAAAAlQL5AACASACngKcAQFIAp0ATdBmAAAAAAAAAAAAAAAAgAAAAAAAAPoAAAAAAAAB9AAAAAAAA9CQQAIBIAKhAp8BASACoAAxYJGE5yoAByOG8m/BAABlrzEHpAAAADAACAEBIAKiAAwD6ABkAA0CASAC1wKkAgEgArECpQIBIAKrAqYCASACqQKnAQEgAqgAIAABAAAAAIAAAAAgAAAAgAABASACqgAUa0ZVPxAEO5rKAAIBIAKuAqwBASACrQAVGlF0h26AAQEgH0gBASACrwEBwAKwALfQUy9aAfuAAARwAEr4IWjfrczCBladVTTHrr3wnswMmyfXzuoe+trp4UqHS9ejAVK/5EJ7RdoyzdXEOu4PryLU/qfdfoZN4IVSgz4AAAAAD/////gAAAAAAAAABAIBIALAArISAfbC9EcamCIVa+84QCTgkXeoO20rqYBu27rhKSZMdCTjAAkgArcCswEBIAK0AgKRArYCtQAqNgQHBAIATEtAATEtAAAAAAIAAAPoACo2AgMCAgAPQkAAmJaAAAAAAQAAAfQBASACuAIBIAK7ArkCCbf///BgAroC0wAB/AIC2QK+ArwCAWICvQLHAgEgAtEC0QIBIALMAr8CAc4C1ALUAgEgAtUCwQEBIALCAgPNQALEAsMAA6igAgEgAswCxQIBIALJAsYCASACyALHAAHUAgFIAtQC1AIBIALLAsoCASACzwLPAgEgAs8C0QIBIALTAs0CASAC0ALOAgEgAtECzwIBIALUAtQCASAC0gLRAAFIAAFYAgHUAtQC1AABIAEBIALWABrEAAAAIAAAAB6IAzYuAgEgAt0C2AEB9ALZAQHAAtoCASAC3ALbABW/////vL0alKIAEAAVvgAAA7yzZw3BVVACASAC4ALeAQFIAt8AQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAgEgAuMC4QEBIALiAEAzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMwEBIALkAEBVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVQ==")?;
         let mut config = config_cell.parse::<BlockchainConfig>()?;
         config.set_global_id(42)?;
@@ -149,7 +174,13 @@ pub mod tests {
             .with_transport(Arc::new(transport))
             .build()?;
 
-        let mut account = context.get_account_from_cell(account_cell.as_ref())?;
+        Ok(context.get_account_from_cell(account_cell.as_ref())?)
+    }
+
+    #[test]
+    fn gen_test() -> anyhow::Result<()> {
+        let function = qube::functions::get_details();
+        let account = qube_account()?;
 
         match account.run_local(function, [].as_slice()) {
             Ok(output) if output.exit_code == 1 || output.exit_code == 0 => Ok(()),
@@ -158,6 +189,460 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn traced_run_local() -> anyhow::Result<()> {
+        let account = qube_account()?;
+        let options = RunLocalOptions {
+            trace: true,
+            ..Default::default()
+        };
+        let output = account.run_local_ext(qube::functions::get_details(), &[], &options)?;
+        let trace = output.trace.context("trace was not recorded")?;
+
+        assert!(!trace.steps.is_empty());
+        assert!(trace.steps.iter().all(|step| !step.opcode.is_empty()));
+        assert!(trace.steps.iter().any(|step| step.offset.is_some()));
+        assert!(trace.steps.iter().any(|step| step.gas_used.is_some()));
+        assert!(trace.steps.iter().any(|step| step.stack.is_some()));
+
+        let total_gas = trace
+            .steps
+            .iter()
+            .filter_map(|step| step.gas_used)
+            .sum::<u64>();
+        assert!(total_gas > 0 && total_gas <= output.gas_used);
+        Ok(())
+    }
+
+    /// Checks the signature of an external message body against the public
+    /// key stored in the account data, then accepts the message:
+    ///
+    /// ```text
+    /// DROP                ; function selector
+    /// LDU 1 SWAP          ; signature flag
+    /// THROWIFNOT 40
+    /// PUSHINT 512 LDSLICEX
+    /// DUP HASHSU ROT      ; payload hash and signature
+    /// PUSHROOT CTOS PLDU 256
+    /// CHKSIGNU THROWIFNOT 40
+    /// ACCEPT DROP
+    /// ```
+    pub(crate) const SIGNATURE_CHECK_CODE: &[u8] = &[
+        0x30, 0xd3, 0x00, 0x01, 0xf2, 0xa8, 0x81, 0x02, 0x00, 0xd7, 0x18, 0x20, 0xf9, 0x01, 0x58,
+        0xed, 0x44, 0xd0, 0xd7, 0x0b, 0xff, 0xf9, 0x10, 0xf2, 0xa8, 0xf8, 0x00, 0x30,
+    ];
+
+    /// Config of the network with global id 42.
+    pub(crate) fn test_config() -> anyhow::Result<BlockchainConfig> {
+        let config_cell = Boc::decode_base64("te6ccgECjAEACdEAAUBVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVQECA81AIwICAUgFAwEBtwQASgIAIAAAAAAgAAAAA+gCAAAA//8CAAABAAAD/wAAAAABAAAAAQACAUgIBgEBSAcBKxJn29wdZ9vfoQANAA0P/////////8AKAQFICQErEmfb2Jln29wdAA0ADQ//////////wAoCAswUCwIBIA0MAJvTnHQJPFDVaw0gpBKW4KLvlHk4muJ7DXIMx9rrMAF8SqwKM2VYwAnYnYnYnYnY1WsNIKQSluCi75R5OJriew1yDMfa6zABfEqsCjNlWMQCASARDgIBIBAPAJsc46BJ4o29TxaMfd0dgRmwy0xCO12cNXWna+BkJXqkxyqZzsq6wE7E7E7E7E7NvU8WjH3dHYEZsMtMQjtdnDV1p2vgZCV6pMcqmc7KuuAAmxzjoEnijuarW543FloKpnGsmsqEFR2EWHcTk+OORw7gGlgUkSxATsTsTsTsTs7mq1ueNxZaCqZxrJrKhBUdhFh3E5PjjkcO4BpYFJEsYAIBIBMSAJsc46BJ4poyedRm6soO+rtymuULxXD+LMQNWUybAxQQgR7j8jyIQE7E7E7E7E7aMnnUZurKDvq7cprlC8Vw/izEDVlMmwMUEIEe4/I8iGAAmxzjoEnim/wiTl4DrPp9Q31ew2a8g7LEubz9WVlpg2JtfA+O4EKATsTsTsTsTtv8Ik5eA6z6fUN9XsNmvIOyxLm8/VlZaYNibXwPjuBCoAIBIBwVAgEgGRYCASAYFwCbHOOgSeKdQG5lMBnnlWWgVolqZweFI850Dkph5YTa8QoxAZCwDkBOxOxOxOxO3UBuZTAZ55VloFaJamcHhSPOdA5KYeWE2vEKMQGQsA5gAJsc46BJ4qAgRTdO/zFcU7vtGYRzhNIHBaEJKXs1sLCT9I7JIErbwE7E7E7E7E7gIEU3Tv8xXFO77RmEc4TSBwWhCSl7NbCwk/SOySBK2+ACASAbGgCbHOOgSeKgh5tRm2VYuljOyzPozbASCWx1lTe29IZoOw/BvJfAWABOxOxOxOxO4IebUZtlWLpYzssz6M2wEglsdZU3tvSGaDsPwbyXwFggAJsc46BJ4qDjNXjH+NJSDBLCbLfAXZLMaKuI8uerjdSLZeLM16qYgE7E7E7E7E7g4zV4x/jSUgwSwmy3wF2SzGiriPLnq43Ui2XizNeqmKACASAgHQIBIB8eAJsc46BJ4qPAE/2psMUyKXMosxAu2aKoR+b8KPbDnjyEQomDF4o+wE7E7E7E7E7jwBP9qbDFMilzKLMQLtmiqEfm/Cj2w548hEKJgxeKPuAAmxzjoEnip+ycYsetYn48e/+trkcu4EntnaX39Rmn/myoMWhbPtkATsTsTsTsTufsnGLHrWJ+PHv/ra5HLuBJ7Z2l9/UZp/5sqDFoWz7ZIAIBICIhAJsc46BJ4rFH29jr0c5J03A4Ipr63JreP3DzVSE+NFnPtxpb6vD3wE7E7E7E7E7xR9vY69HOSdNwOCKa+tya3j9w81UhPjRZz7caW+rw9+AAmxzjoEniusB2bjpv5ukDfb8WmmsqUT3oHkB+AILEh1SYv5Gjb4cATsTsTsTsTvrAdm46b+bpA32/FpprKlE96B5AfgCCxIdUmL+Ro2+HIAIBIFIkAgEgOyUCASA2JgIBIC4nAQFYKAEBwCkCAUgrKgBCv7d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3AgEgLSwAQb9mZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZwAD37ACASAxLwEBIDAANNgTiAAMAAAAFACMANIDIAAAAJYAGQIBBANIAQEgMgHnpoAABOIAAHUwD4AAAAAjw0YAAIAAE4gAMgAFAB4ABQBMS0AATEtAQAAJxAAAACYloAAAAAAAfQTiAPoASwAAADeqCcQC7gAACcQE4gTiBOIABAABdwLuALuAu4ALcbABdwLuAAtxsAH0Au4AAAAAAAAAACAzAgLPNTQAAwKgAAMUIAIBSDk3AQEgOABC6gAAAAABycOAAAAAAHUwAAAAAAAtxsAAAAABgABVVVVVAQEgOgBC6gAAAAAR4aMAAAAABJPgAAAAAAHJw4AAAAABgABVVVVVAgEgRzwCASBCPQIBIEA+AQEgPwBQXcMAAgAAAAgAAAAQAADDAA27oAD0JAAExLQAwwAAA+gAABOIAAAnEAEBIEEAUF3DAAIAAAAIAAAAEAAAwwANu6AA5OHAATEtAMMAAAPoAAATiAAAJxACASBFQwEBIEQAlNEAAAAAAAAD6AAAAAADk4cA3gAAAADqYAAAAAAAAAAPQkAAAAAAAA9CQAAAAAAAACcQAAAAAACYloAAAAAAI8NGAAAAAOjUpRAAAQEgRgCU0QAAAAAAAAPoAAAAACPDRgDeAAAACSfAAAAAAAAAAA9CQAAAAAAF9eEAAAAAAAAAJxAAAAAAAKfYwAAAAAAjw0YAAAAA6NSlEAACASBNSAIBIEtJAQEgSgAI///ojwEBIEwATdBmAAAAAAAAAAAAAAADAAAAAAAABdwAAAAAAAALuAAAAAAAFuNgQAIBIFBOAQEgTwAxYJGE5yoAByOG8m/BAABlrzEHpAAAADAACAEBIFEADAPoAGQADQIBIIFTAgEgXVQCASBaVQIBIFhWAQEgVwAgAAADhAAAAcIAAAA8AAABwgEBIFkAFGtGVT8QBDuaygABAUhbAQHAXAC30FMAAAAAAAAAcAAPirB7YSr0qmhrx8eoLGJYRzM7d6jD2j+8u3UTTHwspQegJq/oR/FqSXsiwKvisZimExuGVkCZp3m1j3qXGqZTAAAAAAgAAAAAAAAAAAAAAAQCASBpXgIBIGNfAQEgYAICkWJhACo2BAcEAgBMS0ABMS0AAAAAAgAAA+gAKjYCAwICAA9CQACYloAAAAABAAAB9AEBIGQCA81AZ2UCAWJmcgIBIHt7AgEgdmgCAc5+fgIBIH9qAQEgawIDzUBvbAIBSG5tAAG3AAG1AgEgdnACASB0cQIBIHNyAAHUAgFIfn4CASB1dQIBIHl5AgEgfXcCASB6eAIBIHt5AgEgfn4CASB8ewABSAABWAIB1H5+AAEgAQEggAAaxAAAACAAAAAADAMWLgIBIISCAQH0gwABQAIBIIeFAQFIhgBAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACASCKiAEBIIkAQDMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzAQEgiwBAVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVU=")?;
+        let mut config = config_cell.parse::<BlockchainConfig>()?;
+        config.set_global_id(42)?;
+        Ok(config)
+    }
+
+    /// Context with the test config over an empty [`SimpleTransport`],
+    /// which is returned to add accounts.
+    pub(crate) fn test_context() -> anyhow::Result<(BlockchainContext, Arc<SimpleTransport>)> {
+        let config = test_config()?;
+        let transport = Arc::new(SimpleTransport::new(vec![], config.clone())?);
+        let context = BlockchainContextBuilder::new()
+            .with_config(config)
+            .with_transport(transport.clone())
+            .build()?;
+        Ok((context, transport))
+    }
+
+    /// Code cell with the raw instructions and references.
+    pub(crate) fn code_cell(code: &[u8], refs: &[Cell]) -> anyhow::Result<Cell> {
+        let mut builder = CellBuilder::new();
+        builder.store_raw(code, (code.len() * 8) as u16)?;
+        for cell in refs {
+            builder.store_reference(cell.clone())?;
+        }
+        Ok(builder.build()?)
+    }
+
+    /// Active basechain account with the specified code and data.
+    pub(crate) fn test_account(code: Cell, data: Cell) -> anyhow::Result<Account> {
+        Ok(Account {
+            address: IntAddr::Std(StdAddr::new(0, HashBytes([0x11; 32]))),
+            storage_stat: Default::default(),
+            last_trans_lt: 0,
+            balance: CurrencyCollection::new(10_000_000_000),
+            state: AccountState::Active(StateInit {
+                code: Some(code),
+                data: Some(data),
+                ..Default::default()
+            }),
+        })
+    }
+
+    #[test]
+    fn signed_run_local() -> anyhow::Result<()> {
+        let mut config = test_config()?;
+        let mut version = config.get_global_version()?;
+        version.capabilities = GlobalCapabilities::new(
+            version.capabilities.into_inner() | GlobalCapability::CapSignatureWithId as u64,
+        );
+        config.set_global_version(&version)?;
+
+        let (context, _) = test_context()?;
+        context.set_config(config, None);
+
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let data = CellBuilder::build_from(HashBytes(key.verifying_key().to_bytes()))?;
+        let account = BlockchainAccount::new(
+            context,
+            test_account(code_cell(SIGNATURE_CHECK_CODE, &[])?, data)?,
+        );
+
+        let function = Function::builder(AbiVersion::V2_2, "check")
+            .with_headers([AbiHeaderType::Time, AbiHeaderType::Expire])
+            .build();
+        let run = |signature_context| {
+            let options = RunLocalOptions {
+                signer: Some(&key),
+                signature_context,
+                ..Default::default()
+            };
+            account.run_local_ext(&function, &[], &options)
+        };
+
+        let output = run(SignatureContext::SignatureId(42))?;
+        assert!(output.success, "exit code {}", output.exit_code);
+        assert!(output.accepted);
+
+        // The network requires signatures with its id
+        assert!(!run(SignatureContext::Empty)?.success);
+        assert!(!run(SignatureContext::SignatureId(43))?.success);
+        assert!(!account.run_local(&function, &[])?.success);
+        Ok(())
+    }
+
+    #[test]
+    fn run_local_reports_acceptance() -> anyhow::Result<()> {
+        let (context, _) = test_context()?;
+
+        let function = Function::builder(AbiVersion::V2_2, "check")
+            .with_headers([AbiHeaderType::Time, AbiHeaderType::Expire])
+            .build();
+
+        // DROP
+        let account = BlockchainAccount::new(
+            context.clone(),
+            test_account(code_cell(&[0x30], &[])?, Cell::empty_cell())?,
+        );
+        let output = account.run_local(&function, &[])?;
+        assert!(output.success);
+        assert!(!output.accepted);
+
+        // ACCEPT DROP
+        let account = BlockchainAccount::new(
+            context,
+            test_account(code_cell(&[0xf8, 0x00, 0x30], &[])?, Cell::empty_cell())?,
+        );
+        let output = account.run_local(&function, &[])?;
+        assert!(output.success);
+        assert!(output.accepted);
+        Ok(())
+    }
+
+    #[test]
+    fn unaccepted_external_message_is_skipped() -> anyhow::Result<()> {
+        let (context, _) = test_context()?;
+
+        // THROW 50
+        let mut account = BlockchainAccount::new(
+            context,
+            test_account(code_cell(&[0xf2, 0x32], &[])?, Cell::empty_cell())?,
+        );
+
+        // The contract is executed once within the gas credit
+        let function = Function::builder(AbiVersion::V2_2, "check")
+            .with_headers([AbiHeaderType::Time, AbiHeaderType::Expire])
+            .build();
+        let output = account.run_local(&function, &[])?;
+        assert!(!output.success);
+        assert!(!output.accepted);
+        assert_eq!(output.exit_code, 50);
+
+        // No transaction is produced for the message
+        let message = MessageBuilder::new_external_in(account.account().address.clone()).build();
+        assert!(matches!(
+            account.emulate_transaction(&message, true),
+            Err(ExecutionError::TransactionError(TxError::Skipped))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn estimated_fees_match_transaction() -> anyhow::Result<()> {
+        let (context, _) = test_context()?;
+
+        let address = IntAddr::Std(StdAddr::new(0, HashBytes([0x11; 32])));
+        let out_msg = MessageBuilder::new_internal_in(
+            address.clone(),
+            IntAddr::Std(StdAddr::new(0, HashBytes([0x22; 32]))),
+        )
+        .with_value(CurrencyCollection::new(1_000_000_000))
+        .with_bounce(false)
+        .build_cell()?;
+
+        // ACCEPT PUSHREF PUSHINT 1 SENDRAWMSG
+        let code = code_cell(&[0xf8, 0x00, 0x88, 0x71, 0xfb, 0x00], &[out_msg])?;
+        let mut account = BlockchainAccount::new(context, test_account(code, Cell::empty_cell())?);
+
+        let message = MessageBuilder::new_external_in(address).build();
+        let fees = account.estimate_fees(&message)?;
+        let emulated = account.emulate_transaction(&message, false)?;
+        assert_eq!(
+            fees,
+            TransactionFees::from_transaction(&emulated.transaction)?
+        );
+
+        assert_eq!(fees.total_fee, emulated.transaction.total_fees.tokens);
+        assert!(fees.gas_fee > Tokens::ZERO);
+        assert!(fees.action_fee > Tokens::ZERO);
+
+        // Fees collected in the transaction add up to its total fees
+        let TxInfo::Ordinary(info) = emulated.transaction.load_info()? else {
+            anyhow::bail!("unexpected transaction type");
+        };
+        let [total, storage, gas, action_fee, fwd, required] = [
+            fees.total_fee,
+            fees.storage_fee,
+            fees.gas_fee,
+            fees.action_fee,
+            fees.fwd_fee,
+            fees.required_balance,
+        ]
+        .map(Tokens::into_inner);
+        let import_fee = total - storage - gas - action_fee;
+
+        let action = info.action_phase.context("no action phase")?;
+        let out_fwd_fee = action.total_fwd_fees.unwrap_or_default().into_inner() - action_fee;
+        assert_eq!(fwd, import_fee + out_fwd_fee);
+        assert_eq!(required, total + 1_000_000_000 + out_fwd_fee);
+        Ok(())
+    }
+
+    #[test]
+    fn emulated_transactions_advance_account() -> anyhow::Result<()> {
+        let (context, _) = test_context()?;
+
+        let address = IntAddr::Std(StdAddr::new(0, HashBytes([0x11; 32])));
+        let message = MessageBuilder::new_external_in(address.clone()).build();
+
+        // ACCEPT DROP
+        let code = code_cell(&[0xf8, 0x00, 0x30], &[])?;
+        let mut account =
+            BlockchainAccount::new(context.clone(), test_account(code, Cell::empty_cell())?);
+
+        let first = account.emulate_transaction(&message, true)?.transaction;
+        let last_transaction_id = account.last_transaction_id().copied();
+        assert_eq!(last_transaction_id.map(|id| id.lt), Some(first.lt));
+
+        // The next transaction is linked to the emulated one
+        let second = account.emulate_transaction(&message, true)?.transaction;
+        assert_eq!(second.prev_trans_lt, first.lt);
+        assert_eq!(
+            Some(second.prev_trans_hash),
+            last_transaction_id.map(|id| id.hash)
+        );
+
+        // ACCEPT PUSHREF PUSHINT 160 SENDRAWMSG, sends the whole balance
+        // and deletes the account
+        let out_msg = MessageBuilder::new_internal_in(
+            address.clone(),
+            IntAddr::Std(StdAddr::new(0, HashBytes([0x22; 32]))),
+        )
+        .with_bounce(false)
+        .build_cell()?;
+        let code = code_cell(
+            &[0xf8, 0x00, 0x88, 0x81, 0x00, 0xa0, 0xfb, 0x00],
+            &[out_msg],
+        )?;
+        let mut account = BlockchainAccount::new(context, test_account(code, Cell::empty_cell())?);
+
+        let emulated = account.emulate_transaction(&message, true)?;
+        assert!(emulated.account.is_none());
+        assert_eq!(account.status(), AccountStatus::NotExists);
+        assert_eq!(
+            account.last_transaction_id().copied(),
+            Some(LastTransactionId {
+                lt: emulated.transaction.lt,
+                hash: *CellBuilder::build_from(&emulated.transaction)?.repr_hash(),
+            })
+        );
+        assert_eq!(account.account().balance, CurrencyCollection::ZERO);
+        Ok(())
+    }
+
+    #[test]
+    fn refresh_account_state() -> anyhow::Result<()> {
+        let (context, transport) = test_context()?;
+
+        let address = StdAddr::new(0, HashBytes([0x11; 32]));
+        let active = test_account(code_cell(&[0x30], &[])?, Cell::empty_cell())?;
+        let set_state = |state: AccountState, lt: u64| -> anyhow::Result<()> {
+            let account = Account {
+                state,
+                last_trans_lt: lt + 1,
+                ..active.clone()
+            };
+            transport.set_account(
+                address.clone(),
+                ShardAccount {
+                    account: Lazy::new(&OptionalAccount(Some(account)))?,
+                    last_trans_hash: HashBytes([lt as u8; 32]),
+                    last_trans_lt: lt,
+                },
+            );
+            Ok(())
+        };
+        let refresh = |account: &mut BlockchainAccount| {
+            account
+                .refresh()
+                .now_or_never()
+                .context("refresh is not ready")?
+        };
+
+        let mut account = BlockchainAccount::new(context, active.clone());
+        set_state(active.state.clone(), 10)?;
+        assert!(refresh(&mut account)?);
+        assert_eq!(account.status(), AccountStatus::Active);
+        assert_eq!(
+            account.last_transaction_id().copied(),
+            Some(LastTransactionId {
+                lt: 10,
+                hash: HashBytes([10; 32]),
+            })
+        );
+
+        // Same last transaction
+        assert!(!refresh(&mut account)?);
+        assert_eq!(account.status(), AccountStatus::Active);
+
+        set_state(AccountState::Frozen(HashBytes([1; 32])), 20)?;
+        assert!(refresh(&mut account)?);
+        assert_eq!(account.status(), AccountStatus::Frozen);
+
+        set_state(AccountState::Uninit, 30)?;
+        assert!(refresh(&mut account)?);
+        assert_eq!(account.status(), AccountStatus::Uninit);
+        assert_eq!(account.last_transaction_id().map(|id| id.lt), Some(30));
+
+        transport.remove_account(&address);
+        assert!(refresh(&mut account)?);
+        assert_eq!(account.status(), AccountStatus::NotExists);
+        assert!(account.last_transaction_id().is_none());
+
+        assert!(!refresh(&mut account)?);
+        assert_eq!(account.status(), AccountStatus::NotExists);
+
+        set_state(active.state.clone(), 40)?;
+        assert!(refresh(&mut account)?);
+        assert_eq!(account.status(), AccountStatus::Active);
+        Ok(())
+    }
+
+    #[test]
+    fn refresh_config_by_seqno() -> anyhow::Result<()> {
+        let config = test_config()?;
+        let transport = Arc::new(SimpleTransport::new(vec![], config.clone())?);
+        let context = BlockchainContextBuilder::new()
+            .with_transport(transport.clone())
+            .build_with_latest_config()
+            .now_or_never()
+            .context("build is not ready")??;
+        let refresh = || {
+            context
+                .refresh_config()
+                .now_or_never()
+                .context("refresh is not ready")?
+        };
+        assert_eq!(context.config_seqno(), Some(0));
+
+        let utime = context.clock().now_sec_u64() as u32;
+        let parsed = context.parsed_config(utime)?;
+        assert!(Arc::ptr_eq(&parsed, &context.parsed_config(utime)?));
+
+        // The config is replaced only with a new key block
+        let mut updated = config.clone();
+        updated.set_global_id(43)?;
+        transport.set_config(updated.clone(), 0);
+        assert!(!refresh()?);
+        assert_eq!(context.config().get_global_id()?, 42);
+        assert!(Arc::ptr_eq(&parsed, &context.parsed_config(utime)?));
+
+        transport.set_config(updated, 1);
+        assert!(refresh()?);
+        assert_eq!(context.config_seqno(), Some(1));
+        let clone = context.clone();
+        assert_eq!(clone.config().get_global_id()?, 43);
+        let previous = parsed;
+        let parsed = clone.parsed_config(utime)?;
+        assert!(!Arc::ptr_eq(&previous, &parsed));
+        // Clones share the parsed config
+        assert!(Arc::ptr_eq(&parsed, &context.parsed_config(utime)?));
+        assert!(!refresh()?);
+
+        // Older key block from a lagging node doesn't roll the config back
+        transport.set_config(config.clone(), 0);
+        assert!(!refresh()?);
+        assert_eq!(context.config_seqno(), Some(1));
+        assert_eq!(context.config().get_global_id()?, 43);
+
+        // Same params are parsed again after the explicit replacement
+        context.set_config((*context.config()).clone(), None);
+        assert!(!Arc::ptr_eq(&parsed, &clone.parsed_config(utime)?));
+        assert!(refresh()?);
+        assert_eq!(context.config_seqno(), Some(0));
+        Ok(())
+    }
+
+    #[test]
+    fn getters_use_time_and_seed_overrides() -> anyhow::Result<()> {
+        let (context, _) = test_context()?;
+
+        // DROP NOW RANDSEED
+        let account = BlockchainAccount::new(
+            context,
+            test_account(
+                code_cell(&[0x30, 0xf8, 0x23, 0xf8, 0x26], &[])?,
+                Cell::empty_cell(),
+            )?,
+        );
+
+        let run = |overrides: StateOverrides| -> anyhow::Result<(u32, BigUint)> {
+            account.run_getter_typed_with_overrides("env", (), &overrides)
+        };
+
+        let overrides = StateOverrides::default()
+            .with_block_utime(1700000000)
+            .with_rand_seed(HashBytes([1; 32]));
+        let (now, seed) = run(overrides.clone())?;
+        assert_eq!(now, 1700000000);
+        assert_eq!(run(overrides.clone())?.1, seed);
+        assert_ne!(run(overrides.with_rand_seed(HashBytes([2; 32])))?.1, seed);
+
+        // Traced runs see the same environment
+        let overrides = StateOverrides::default().with_block_utime(1600000000);
+        let (output, _) = account.run_getter_traced_with_overrides("env", &[], &overrides)?;
+        assert!(output.success);
+        let (now, _) = <(u32, BigUint)>::from_stack(output.stack.into_iter().collect())?;
+        assert_eq!(now, 1600000000);
+
+        // Other overrides still use the plain getter
+        let overrides = StateOverrides::default().with_balance(CurrencyCollection::new(1));
+        assert!(
+            account
+                .run_getter_with_overrides("env", &[], &overrides)?
+                .success
+        );
+        Ok(())
+    }
+
     #[nekoton_proc::abi("core/src/test/abi.json")]
     pub mod qube {}
 }
@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+
+use tycho_types::cell::{Cell, HashBytes};
+use tycho_types::models::{Account, AccountState, BlockchainConfig, CurrencyCollection, StateInit};
+
+/// Changes applied to the emulated environment of a local call.
+///
+/// Overrides are applied to a copy, the stored account is never modified.
+#[derive(Debug, Default, Clone)]
+pub struct StateOverrides {
+    pub balance: Option<CurrencyCollection>,
+    /// Replaces the code and activates the account if needed.
+    pub code: Option<Cell>,
+    pub data: Option<Cell>,
+    pub last_trans_lt: Option<u64>,
+    /// Block time in seconds.
+    pub block_utime: Option<u32>,
+    /// Block random seed, mixed with the account address as in transactions.
+    pub rand_seed: Option<HashBytes>,
+    /// Raw config params by index.
+    pub config_params: BTreeMap<u32, Cell>,
+}
+
+impl StateOverrides {
+    pub fn with_balance<T: Into<CurrencyCollection>>(mut self, balance: T) -> Self {
+        self.balance = Some(balance.into());
+        self
+    }
+
+    pub fn with_code(mut self, code: Cell) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    pub fn with_data(mut self, data: Cell) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    pub fn with_last_trans_lt(mut self, lt: u64) -> Self {
+        self.last_trans_lt = Some(lt);
+        self
+    }
+
+    pub fn with_block_utime(mut self, utime: u32) -> Self {
+        self.block_utime = Some(utime);
+        self
+    }
+
+    pub fn with_rand_seed(mut self, rand_seed: HashBytes) -> Self {
+        self.rand_seed = Some(rand_seed);
+        self
+    }
+
+    pub fn with_config_param(mut self, index: u32, value: Cell) -> Self {
+        self.config_params.insert(index, value);
+        self
+    }
+
+    /// Returns a copy of the account with overridden fields.
+    pub fn apply_to_account(&self, account: &Account) -> Account {
+        let mut account = account.clone();
+
+        if let Some(balance) = &self.balance {
+            account.balance = balance.clone();
+        }
+        if let Some(lt) = self.last_trans_lt {
+            account.last_trans_lt = lt;
+        }
+
+        if self.code.is_some() || self.data.is_some() {
+            let mut state = match account.state {
+                AccountState::Active(state) => state,
+                _ => StateInit::default(),
+            };
+            if let Some(code) = &self.code {
+                state.code = Some(code.clone());
+            }
+            if let Some(data) = &self.data {
+                state.data = Some(data.clone());
+            }
+            account.state = AccountState::Active(state);
+        }
+
+        account
+    }
+
+    /// Returns a copy of the config with overridden params.
+    pub fn apply_to_config(&self, config: &BlockchainConfig) -> anyhow::Result<BlockchainConfig> {
+        let mut config = config.clone();
+        for (index, value) in &self.config_params {
+            config.params.set_raw(*index, value.clone())?;
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tycho_types::cell::CellBuilder;
+    use tycho_types::models::{IntAddr, StdAddr};
+    use tycho_types::num::Tokens;
+
+    use super::*;
+
+    #[test]
+    fn overrides_do_not_touch_original() -> anyhow::Result<()> {
+        let account = Account {
+            address: IntAddr::Std(StdAddr::new(0, HashBytes::ZERO)),
+            storage_stat: Default::default(),
+            last_trans_lt: 10,
+            balance: Tokens::new(1).into(),
+            state: AccountState::Uninit,
+        };
+
+        let code = CellBuilder::build_from(0xdeadbeefu32)?;
+        let overrides = StateOverrides::default()
+            .with_balance(Tokens::new(100))
+            .with_code(code.clone())
+            .with_last_trans_lt(20);
+
+        let patched = overrides.apply_to_account(&account);
+        assert_eq!(patched.balance.tokens, Tokens::new(100));
+        assert_eq!(patched.last_trans_lt, 20);
+        match &patched.state {
+            AccountState::Active(state) => {
+                assert_eq!(state.code.as_ref(), Some(&code));
+                assert!(state.data.is_none());
+            }
+            _ => panic!("account must be active"),
+        }
+
+        assert_eq!(account.balance.tokens, Tokens::new(1));
+        assert_eq!(account.state, AccountState::Uninit);
+
+        let unchanged = StateOverrides::default().apply_to_account(&account);
+        assert_eq!(unchanged, account);
+        Ok(())
+    }
+}
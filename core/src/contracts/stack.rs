@@ -0,0 +1,399 @@
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::ToPrimitive;
+use tycho_types::cell::{Cell, CellBuilder, Load};
+use tycho_types::models::{IntAddr, StdAddr};
+use tycho_types::num::Tokens;
+use tycho_vm::{OwnedCellSlice, RcStackValue, SafeRc, VmGetterError};
+
+use super::tracer::TraceError;
+
+/// Mismatch between the TVM stack and the expected Rust types.
+#[derive(thiserror::Error, Debug)]
+pub enum StackError {
+    #[error("expected {expected} stack values, got {actual}")]
+    LengthMismatch { expected: usize, actual: usize },
+    #[error("expected {expected}, got {actual}")]
+    TypeMismatch {
+        expected: &'static str,
+        actual: String,
+    },
+    #[error("integer {value} is out of range for {ty}")]
+    IntOutOfRange { value: BigInt, ty: &'static str },
+    #[error("invalid {ty}: {reason}")]
+    InvalidValue { ty: &'static str, reason: String },
+    #[error("stack value #{index}: {source}")]
+    At {
+        index: usize,
+        #[source]
+        source: Box<StackError>,
+    },
+}
+
+impl StackError {
+    fn at(self, index: usize) -> Self {
+        Self::At {
+            index,
+            source: Box::new(self),
+        }
+    }
+}
+
+/// Error of a typed getter call.
+#[derive(thiserror::Error, Debug)]
+pub enum GetterError {
+    #[error(transparent)]
+    Vm(#[from] VmGetterError),
+    #[error("getter failed with exit code {0}")]
+    ExitCode(i32),
+    #[error("failed to encode getter arguments: {0}")]
+    Args(#[source] StackError),
+    #[error("failed to decode getter result: {0}")]
+    Result(#[source] StackError),
+    #[error("failed to trace the getter: {0}")]
+    Trace(#[from] TraceError),
+}
+
+/// Type which can be pushed to the TVM stack.
+pub trait IntoStackValue {
+    fn into_stack_value(self) -> Result<RcStackValue, StackError>;
+}
+
+/// Type which can be read from the TVM stack.
+pub trait FromStackValue: Sized {
+    fn from_stack_value(value: RcStackValue) -> Result<Self, StackError>;
+}
+
+/// Getter arguments, pushed to the stack in order.
+pub trait StackArgs {
+    fn into_stack(self) -> Result<Vec<RcStackValue>, StackError>;
+}
+
+/// Getter results, read from the stack in order.
+pub trait StackResult: Sized {
+    fn from_stack(stack: Vec<RcStackValue>) -> Result<Self, StackError>;
+}
+
+fn type_mismatch(expected: &'static str, value: &RcStackValue) -> StackError {
+    StackError::TypeMismatch {
+        expected,
+        actual: format!("{:?}", value.ty()),
+    }
+}
+
+impl IntoStackValue for RcStackValue {
+    fn into_stack_value(self) -> Result<RcStackValue, StackError> {
+        Ok(self)
+    }
+}
+
+impl FromStackValue for RcStackValue {
+    fn from_stack_value(value: RcStackValue) -> Result<Self, StackError> {
+        Ok(value)
+    }
+}
+
+impl IntoStackValue for BigInt {
+    fn into_stack_value(self) -> Result<RcStackValue, StackError> {
+        Ok(RcStackValue::new_dyn_value(self))
+    }
+}
+
+impl FromStackValue for BigInt {
+    fn from_stack_value(value: RcStackValue) -> Result<Self, StackError> {
+        match value.clone().into_int() {
+            Ok(int) => Ok(SafeRc::unwrap_or_clone(int)),
+            Err(_) => Err(type_mismatch("integer", &value)),
+        }
+    }
+}
+
+impl IntoStackValue for BigUint {
+    fn into_stack_value(self) -> Result<RcStackValue, StackError> {
+        BigInt::from(self).into_stack_value()
+    }
+}
+
+impl FromStackValue for BigUint {
+    fn from_stack_value(value: RcStackValue) -> Result<Self, StackError> {
+        let int = BigInt::from_stack_value(value)?;
+        match int.sign() {
+            Sign::Minus => Err(StackError::IntOutOfRange {
+                value: int,
+                ty: "BigUint",
+            }),
+            _ => Ok(int.magnitude().clone()),
+        }
+    }
+}
+
+macro_rules! impl_primitive_int {
+    ($($ty:ty => $to:ident),*$(,)?) => {$(
+        impl IntoStackValue for $ty {
+            fn into_stack_value(self) -> Result<RcStackValue, StackError> {
+                BigInt::from(self).into_stack_value()
+            }
+        }
+
+        impl FromStackValue for $ty {
+            fn from_stack_value(value: RcStackValue) -> Result<Self, StackError> {
+                let int = BigInt::from_stack_value(value)?;
+                int.$to().ok_or(StackError::IntOutOfRange {
+                    value: int,
+                    ty: stringify!($ty),
+                })
+            }
+        }
+    )*};
+}
+
+impl_primitive_int! {
+    u8 => to_u8, u16 => to_u16, u32 => to_u32, u64 => to_u64, u128 => to_u128,
+    i8 => to_i8, i16 => to_i16, i32 => to_i32, i64 => to_i64, i128 => to_i128,
+}
+
+/// TVM booleans are `-1` for true and `0` for false.
+impl IntoStackValue for bool {
+    fn into_stack_value(self) -> Result<RcStackValue, StackError> {
+        BigInt::from(-(self as i8)).into_stack_value()
+    }
+}
+
+impl FromStackValue for bool {
+    fn from_stack_value(value: RcStackValue) -> Result<Self, StackError> {
+        let int = BigInt::from_stack_value(value)?;
+        Ok(int.sign() != Sign::NoSign)
+    }
+}
+
+impl IntoStackValue for Tokens {
+    fn into_stack_value(self) -> Result<RcStackValue, StackError> {
+        self.into_inner().into_stack_value()
+    }
+}
+
+impl FromStackValue for Tokens {
+    fn from_stack_value(value: RcStackValue) -> Result<Self, StackError> {
+        let tokens = Tokens::new(u128::from_stack_value(value)?);
+        if !tokens.is_valid() {
+            return Err(StackError::InvalidValue {
+                ty: "Tokens",
+                reason: "value is too big".to_owned(),
+            });
+        }
+        Ok(tokens)
+    }
+}
+
+impl IntoStackValue for Cell {
+    fn into_stack_value(self) -> Result<RcStackValue, StackError> {
+        Ok(RcStackValue::new_dyn_value(self))
+    }
+}
+
+impl FromStackValue for Cell {
+    fn from_stack_value(value: RcStackValue) -> Result<Self, StackError> {
+        match value.clone().into_cell() {
+            Ok(cell) => Ok(SafeRc::unwrap_or_clone(cell)),
+            Err(_) => Err(type_mismatch("cell", &value)),
+        }
+    }
+}
+
+impl IntoStackValue for OwnedCellSlice {
+    fn into_stack_value(self) -> Result<RcStackValue, StackError> {
+        Ok(RcStackValue::new_dyn_value(self))
+    }
+}
+
+impl FromStackValue for OwnedCellSlice {
+    fn from_stack_value(value: RcStackValue) -> Result<Self, StackError> {
+        match value.clone().into_cell_slice() {
+            Ok(slice) => Ok(SafeRc::unwrap_or_clone(slice)),
+            Err(_) => Err(type_mismatch("slice", &value)),
+        }
+    }
+}
+
+/// Addresses are passed as slices.
+impl IntoStackValue for IntAddr {
+    fn into_stack_value(self) -> Result<RcStackValue, StackError> {
+        let cell = CellBuilder::build_from(self).map_err(|e| StackError::InvalidValue {
+            ty: "address",
+            reason: e.to_string(),
+        })?;
+        OwnedCellSlice::new_allow_exotic(cell).into_stack_value()
+    }
+}
+
+impl FromStackValue for IntAddr {
+    fn from_stack_value(value: RcStackValue) -> Result<Self, StackError> {
+        let slice = OwnedCellSlice::from_stack_value(value)?;
+        IntAddr::load_from(&mut slice.apply()).map_err(|e| StackError::InvalidValue {
+            ty: "address",
+            reason: e.to_string(),
+        })
+    }
+}
+
+impl IntoStackValue for StdAddr {
+    fn into_stack_value(self) -> Result<RcStackValue, StackError> {
+        IntAddr::Std(self).into_stack_value()
+    }
+}
+
+impl FromStackValue for StdAddr {
+    fn from_stack_value(value: RcStackValue) -> Result<Self, StackError> {
+        match IntAddr::from_stack_value(value)? {
+            IntAddr::Std(addr) => Ok(addr),
+            IntAddr::Var(_) => Err(StackError::InvalidValue {
+                ty: "address",
+                reason: "expected a standard address".to_owned(),
+            }),
+        }
+    }
+}
+
+/// Homogeneous TVM tuple.
+impl<T: IntoStackValue> IntoStackValue for Vec<T> {
+    fn into_stack_value(self) -> Result<RcStackValue, StackError> {
+        let items = self
+            .into_iter()
+            .enumerate()
+            .map(|(i, item)| item.into_stack_value().map_err(|e| e.at(i)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(RcStackValue::new_dyn_value(items))
+    }
+}
+
+impl<T: FromStackValue> FromStackValue for Vec<T> {
+    fn from_stack_value(value: RcStackValue) -> Result<Self, StackError> {
+        let tuple = match value.clone().into_tuple() {
+            Ok(tuple) => SafeRc::unwrap_or_clone(tuple),
+            Err(_) => return Err(type_mismatch("tuple", &value)),
+        };
+        tuple
+            .into_iter()
+            .enumerate()
+            .map(|(i, item)| T::from_stack_value(item).map_err(|e| e.at(i)))
+            .collect()
+    }
+}
+
+impl StackArgs for &[RcStackValue] {
+    fn into_stack(self) -> Result<Vec<RcStackValue>, StackError> {
+        Ok(self.to_vec())
+    }
+}
+
+impl StackArgs for Vec<RcStackValue> {
+    fn into_stack(self) -> Result<Vec<RcStackValue>, StackError> {
+        Ok(self)
+    }
+}
+
+impl StackResult for Vec<RcStackValue> {
+    fn from_stack(stack: Vec<RcStackValue>) -> Result<Self, StackError> {
+        Ok(stack)
+    }
+}
+
+macro_rules! impl_tuples {
+    ($($n:literal => ($($idx:tt: $ty:ident),*)),*$(,)?) => {$(
+        /// Heterogeneous TVM tuple.
+        impl<$($ty: IntoStackValue),*> IntoStackValue for ($($ty,)*) {
+            fn into_stack_value(self) -> Result<RcStackValue, StackError> {
+                let items = StackArgs::into_stack(self)?;
+                Ok(RcStackValue::new_dyn_value(items))
+            }
+        }
+
+        impl<$($ty: FromStackValue),*> FromStackValue for ($($ty,)*) {
+            fn from_stack_value(value: RcStackValue) -> Result<Self, StackError> {
+                let tuple = match value.clone().into_tuple() {
+                    Ok(tuple) => SafeRc::unwrap_or_clone(tuple),
+                    Err(_) => return Err(type_mismatch("tuple", &value)),
+                };
+                <($($ty,)*)>::from_stack(tuple)
+            }
+        }
+
+        impl<$($ty: IntoStackValue),*> StackArgs for ($($ty,)*) {
+            #[allow(unused_mut)]
+            fn into_stack(self) -> Result<Vec<RcStackValue>, StackError> {
+                let mut stack = Vec::with_capacity($n);
+                $(stack.push(self.$idx.into_stack_value().map_err(|e| e.at($idx))?);)*
+                Ok(stack)
+            }
+        }
+
+        impl<$($ty: FromStackValue),*> StackResult for ($($ty,)*) {
+            #[allow(unused_mut, unused_variables)]
+            fn from_stack(stack: Vec<RcStackValue>) -> Result<Self, StackError> {
+                if stack.len() != $n {
+                    return Err(StackError::LengthMismatch {
+                        expected: $n,
+                        actual: stack.len(),
+                    });
+                }
+                let mut items = stack.into_iter();
+                Ok(($(
+                    $ty::from_stack_value(items.next().unwrap()).map_err(|e| e.at($idx))?,
+                )*))
+            }
+        }
+    )*};
+}
+
+impl_tuples! {
+    0 => (),
+    1 => (0: T0),
+    2 => (0: T0, 1: T1),
+    3 => (0: T0, 1: T1, 2: T2),
+    4 => (0: T0, 1: T1, 2: T2, 3: T3),
+    5 => (0: T0, 1: T1, 2: T2, 3: T3, 4: T4),
+    6 => (0: T0, 1: T1, 2: T2, 3: T3, 4: T4, 5: T5),
+    7 => (0: T0, 1: T1, 2: T2, 3: T3, 4: T4, 5: T5, 6: T6),
+    8 => (0: T0, 1: T1, 2: T2, 3: T3, 4: T4, 5: T5, 6: T6, 7: T7),
+}
+
+#[cfg(test)]
+mod tests {
+    use tycho_types::cell::HashBytes;
+
+    use super::*;
+
+    #[test]
+    fn round_trip() -> anyhow::Result<()> {
+        let addr = StdAddr::new(0, HashBytes([1; 32]));
+        let stack = (10u32, -5i64, true, addr.clone(), vec![1u8, 2, 3]).into_stack()?;
+        assert_eq!(stack.len(), 5);
+
+        let (a, b, c, d, e) = <(u32, i64, bool, StdAddr, Vec<u8>)>::from_stack(stack)?;
+        assert_eq!(a, 10);
+        assert_eq!(b, -5);
+        assert!(c);
+        assert_eq!(d, addr);
+        assert_eq!(e, vec![1, 2, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn shape_mismatch() -> anyhow::Result<()> {
+        let stack = (1u8, 2u8).into_stack()?;
+        assert!(matches!(
+            <(u8,)>::from_stack(stack.clone()),
+            Err(StackError::LengthMismatch {
+                expected: 1,
+                actual: 2
+            })
+        ));
+
+        let err = <(u8, Cell)>::from_stack(stack).unwrap_err();
+        assert!(matches!(err, StackError::At { index: 1, .. }));
+
+        let stack = (300u32,).into_stack()?;
+        let err = <(u8,)>::from_stack(stack).unwrap_err();
+        assert!(err.to_string().contains("out of range"));
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+use tycho_types::abi::{Contract, NamedAbiValue};
+use tycho_types::cell::Cell;
+use tycho_types::models::{Account, AccountState};
+
+use crate::error::ExecutionError;
+
+/// Decodes the persistent fields declared by the ABI from the account data.
+///
+/// Any account state can be used, including historical ones. For ABI 2.1+
+/// the fields start with the public key, timestamp and constructor flag.
+pub fn decode_storage(
+    account: &Account,
+    contract: &Contract,
+) -> Result<Vec<NamedAbiValue>, ExecutionError> {
+    let AccountState::Active(state) = &account.state else {
+        return Err(ExecutionError::AccountNotActive(account.address.clone()));
+    };
+    let Some(data) = &state.data else {
+        return Err(anyhow::anyhow!("account {} has no data", account.address).into());
+    };
+    decode_storage_data(data, contract)
+}
+
+/// Decodes the persistent fields declared by the ABI from the data cell.
+pub fn decode_storage_data(
+    data: &Cell,
+    contract: &Contract,
+) -> Result<Vec<NamedAbiValue>, ExecutionError> {
+    if contract.fields.is_empty() {
+        return Err(anyhow::anyhow!("ABI has no storage fields").into());
+    }
+
+    let mut slice = data.as_slice()?;
+    let values = NamedAbiValue::load_tuple(&contract.fields, contract.abi_version, &mut slice)?;
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use tycho_types::abi::AbiValue;
+    use tycho_types::cell::{CellBuilder, HashBytes};
+    use tycho_types::models::{IntAddr, StateInit, StdAddr};
+
+    use super::*;
+
+    const ABI: &str = r#"{
+        "ABI version": 2,
+        "version": "2.2",
+        "header": [],
+        "functions": [],
+        "events": [],
+        "data": [],
+        "fields": [
+            {"name": "_pubkey", "type": "uint256"},
+            {"name": "_timestamp", "type": "uint64"},
+            {"name": "_constructorFlag", "type": "bool"},
+            {"name": "counter", "type": "uint32"}
+        ]
+    }"#;
+
+    #[test]
+    fn decode_account_storage() -> anyhow::Result<()> {
+        let contract = serde_json::from_str::<Contract>(ABI)?;
+
+        let mut builder = CellBuilder::new();
+        builder.store_u256(&HashBytes([1; 32]))?;
+        builder.store_u64(1700000000000)?;
+        builder.store_bit_one()?;
+        builder.store_u32(42)?;
+
+        let mut account = Account {
+            address: IntAddr::Std(StdAddr::new(0, HashBytes::ZERO)),
+            storage_stat: Default::default(),
+            last_trans_lt: 0,
+            balance: Default::default(),
+            state: AccountState::Active(StateInit {
+                data: Some(builder.build()?),
+                ..Default::default()
+            }),
+        };
+
+        let values = decode_storage(&account, &contract)?;
+        assert_eq!(values.len(), 4);
+        assert_eq!(
+            values[0].value,
+            AbiValue::Uint(256, BigUint::from_bytes_be(&[1; 32]))
+        );
+        assert_eq!(values[2].value, AbiValue::Bool(true));
+        assert_eq!(values[3].value, AbiValue::Uint(32, BigUint::from(42u32)));
+
+        account.state = AccountState::Uninit;
+        assert!(matches!(
+            decode_storage(&account, &contract),
+            Err(ExecutionError::AccountNotActive(_))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,264 @@
+use std::fmt::Write;
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+use tracing::field::{Field, Visit};
+use tracing::level_filters::{LevelFilter, STATIC_MAX_LEVEL};
+use tracing::span::{Attributes, Id, Record};
+use tracing::subscriber::Interest;
+use tracing::{Dispatch, Event, Metadata, Subscriber};
+
+const VM_TARGET: &str = "tycho_vm";
+
+/// Step-by-step trace of a VM execution.
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionTrace {
+    pub steps: Vec<TraceStep>,
+}
+
+/// Single executed instruction.
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceStep {
+    /// Instruction with its arguments, e.g. `PUSHINT 10`.
+    pub opcode: String,
+    /// Stack before the instruction, from the bottom to the top.
+    /// Each item is formatted by the VM, e.g. `10`, `C{...}` or `[ 1 2 ]`.
+    pub stack: Option<Vec<String>>,
+    /// Hash of the code cell containing the instruction.
+    pub code_hash: Option<String>,
+    /// Offset of the instruction in the code cell, in bits.
+    pub offset: Option<u32>,
+    pub gas_used: Option<u64>,
+    pub gas_remaining: Option<u64>,
+}
+
+impl ExecutionTrace {
+    /// Runs the closure while collecting the VM log produced on this thread.
+    ///
+    /// The trace is built from the `TRACE` level events of `tycho_vm`,
+    /// so it is unavailable when these events are compiled out by
+    /// the `max_level_*` or `release_max_level_*` features of `tracing`
+    /// enabled anywhere in the dependency graph. Events are still passed
+    /// to the subscriber of the application.
+    pub fn record<R, F: FnOnce() -> R>(f: F) -> (R, Result<Self, TraceError>) {
+        if STATIC_MAX_LEVEL < LevelFilter::TRACE {
+            return (f(), Err(TraceError::Disabled(STATIC_MAX_LEVEL)));
+        }
+
+        let collector = tracing::dispatcher::get_default(|inner| TraceCollector {
+            lines: Default::default(),
+            inner: inner.clone(),
+        });
+        let lines = collector.lines.clone();
+
+        let result = tracing::subscriber::with_default(collector, f);
+
+        let lines = std::mem::take(&mut *lines.lock().unwrap());
+        let trace = Self::from_vm_log(lines);
+        if trace.steps.is_empty() {
+            return (result, Err(TraceError::Empty));
+        }
+        (result, Ok(trace))
+    }
+
+    fn from_vm_log(lines: Vec<String>) -> Self {
+        let mut steps = Vec::<TraceStep>::new();
+        let mut next = TraceStep::default();
+        let mut last_gas_remaining = None;
+
+        for line in lines {
+            if let Some(opcode) = line.strip_prefix("execute ") {
+                next.opcode = opcode.to_owned();
+                steps.push(std::mem::take(&mut next));
+            } else if let Some(stack) = line.strip_prefix("stack: ") {
+                next.stack = Some(parse_stack(stack));
+            } else if let Some(position) = line.strip_prefix("code cell hash: ") {
+                let mut parts = position.split(" offset: ");
+                next.code_hash = parts.next().map(|hash| hash.trim().to_owned());
+                next.offset = parts.next().and_then(|offset| offset.trim().parse().ok());
+            } else if let Some(gas) = line.strip_prefix("gas remaining: ") {
+                let Ok(gas) = gas.trim().parse::<u64>() else {
+                    continue;
+                };
+                if let Some(step) = steps.last_mut() {
+                    step.gas_remaining = Some(gas);
+                    step.gas_used = last_gas_remaining.map(|last: u64| last.saturating_sub(gas));
+                }
+                last_gas_remaining = Some(gas);
+            }
+        }
+
+        Self { steps }
+    }
+}
+
+/// Splits the VM stack dump `[ item item ... ]` into top-level items.
+fn parse_stack(stack: &str) -> Vec<String> {
+    let stack = stack.trim();
+    let inner = stack
+        .strip_prefix('[')
+        .and_then(|stack| stack.strip_suffix(']'))
+        .unwrap_or(stack);
+
+    let mut items = Vec::new();
+    let mut item = String::new();
+    let mut depth = 0usize;
+    for c in inner.chars() {
+        match c {
+            '[' | '{' | '(' => depth += 1,
+            ']' | '}' | ')' => depth = depth.saturating_sub(1),
+            c if c.is_whitespace() && depth == 0 => {
+                if !item.is_empty() {
+                    items.push(std::mem::take(&mut item));
+                }
+                continue;
+            }
+            _ => {}
+        }
+        item.push(c);
+    }
+    if !item.is_empty() {
+        items.push(item);
+    }
+    items
+}
+
+/// Error of recording an [`ExecutionTrace`].
+#[derive(thiserror::Error, Debug, Clone, Copy)]
+pub enum TraceError {
+    #[error("VM tracing is compiled out by the static max level of `tracing` ({0})")]
+    Disabled(LevelFilter),
+    #[error("no executed instructions were recorded")]
+    Empty,
+}
+
+/// Collects VM events and forwards everything to the outer subscriber.
+struct TraceCollector {
+    lines: Arc<Mutex<Vec<String>>>,
+    inner: Dispatch,
+}
+
+impl Subscriber for TraceCollector {
+    fn register_callsite(&self, metadata: &'static Metadata<'static>) -> Interest {
+        // Interest is cached for all dispatchers, so the callsite must
+        // be rechecked each time to not leak VM events to other threads
+        _ = self.inner.register_callsite(metadata);
+        Interest::sometimes()
+    }
+
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.target().starts_with(VM_TARGET) || self.inner.enabled(metadata)
+    }
+
+    fn max_level_hint(&self) -> Option<LevelFilter> {
+        Some(LevelFilter::TRACE)
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        self.inner.new_span(span)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        self.inner.record(span, values);
+    }
+
+    fn record_follows_from(&self, span: &Id, follows: &Id) {
+        self.inner.record_follows_from(span, follows);
+    }
+
+    fn event(&self, event: &Event<'_>) {
+        if event.metadata().target().starts_with(VM_TARGET) {
+            let mut visitor = MessageVisitor::default();
+            event.record(&mut visitor);
+            self.lines.lock().unwrap().push(visitor.0);
+        }
+        if self.inner.enabled(event.metadata()) {
+            self.inner.event(event);
+        }
+    }
+
+    fn enter(&self, span: &Id) {
+        self.inner.enter(span);
+    }
+
+    fn exit(&self, span: &Id) {
+        self.inner.exit(span);
+    }
+
+    fn clone_span(&self, span: &Id) -> Id {
+        self.inner.clone_span(span)
+    }
+
+    fn try_close(&self, span: Id) -> bool {
+        self.inner.try_close(span)
+    }
+}
+
+#[derive(Default)]
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            _ = write!(self.0, "{value:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_vm_log() {
+        let lines = [
+            "stack: [ ]",
+            "code cell hash: 4A1F offset: 0",
+            "execute PUSHINT 10",
+            "gas remaining: 982",
+            "stack: [ 10 ]",
+            "code cell hash: 4A1F offset: 8",
+            "execute INC",
+            "gas remaining: 964",
+        ]
+        .map(String::from)
+        .to_vec();
+
+        let trace = ExecutionTrace::from_vm_log(lines);
+        assert_eq!(trace.steps.len(), 2);
+
+        assert_eq!(trace.steps[0].opcode, "PUSHINT 10");
+        assert_eq!(trace.steps[0].stack, Some(Vec::new()));
+        assert_eq!(trace.steps[0].offset, Some(0));
+        assert_eq!(trace.steps[0].gas_used, None);
+
+        assert_eq!(trace.steps[1].stack, Some(vec!["10".to_owned()]));
+        assert_eq!(trace.steps[1].opcode, "INC");
+        assert_eq!(trace.steps[1].code_hash.as_deref(), Some("4A1F"));
+        assert_eq!(trace.steps[1].offset, Some(8));
+        assert_eq!(trace.steps[1].gas_used, Some(18));
+        assert_eq!(trace.steps[1].gas_remaining, Some(964));
+    }
+
+    #[test]
+    fn empty_trace_is_an_error() {
+        let (result, trace) = ExecutionTrace::record(|| 1);
+        assert_eq!(result, 1);
+        assert!(trace.is_err());
+    }
+
+    #[test]
+    fn parse_nested_stack() {
+        assert_eq!(
+            parse_stack("[ 1 [ 2 C{AB CD} ] CS{Cell{0012} bits: 0..8; refs: 0..0} () ]"),
+            [
+                "1",
+                "[ 2 C{AB CD} ]",
+                "CS{Cell{0012} bits: 0..8; refs: 0..0}",
+                "()"
+            ]
+        );
+    }
+}
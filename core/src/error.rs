@@ -1,5 +1,11 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use anyhow::Context;
 use tycho_types::abi::AbiType;
-use tycho_types::models::{ComputePhaseSkipReason, IntAddr};
+use tycho_types::models::{
+    BouncePhase, ComputePhase, ComputePhaseSkipReason, IntAddr, Transaction, TxInfo,
+};
 
 #[derive(thiserror::Error, Debug)]
 pub enum ExecutionError {
@@ -17,8 +23,18 @@ pub enum ExecutionError {
     #[error("Invalid address type")]
     InvalidAddressType,
 
-    #[error("Compute hase skipped. Reason: {0:?}")]
+    #[error("Compute phase skipped. Reason: {0:?}")]
     ComputePhaseSkipped(ComputePhaseSkipReason),
+    #[error("Compute phase failed: {0}")]
+    ComputePhaseFailed(ComputeError),
+    #[error("Action phase failed: {name} (result code {result_code}, result arg {result_arg:?})")]
+    ActionPhaseFailed {
+        result_code: i32,
+        result_arg: Option<i32>,
+        name: &'static str,
+    },
+    #[error("Message bounced. {0}")]
+    Bounced(Box<ExecutionError>),
     #[error("Transaction error {0:}")]
     TransactionError(#[from] tycho_executor::TxError),
 
@@ -27,3 +43,290 @@ pub enum ExecutionError {
     #[error("Error: {0}")]
     Other(#[from] anyhow::Error),
 }
+
+impl ExecutionError {
+    /// Returns an error if any phase of the transaction has failed.
+    ///
+    /// Failures which caused a bounce are wrapped into [`ExecutionError::Bounced`].
+    pub fn check_transaction(
+        tx: &Transaction,
+        errors: Option<&AbiErrors>,
+    ) -> Result<(), ExecutionError> {
+        let TxInfo::Ordinary(info) = tx.load_info()? else {
+            return Ok(());
+        };
+
+        let error = match &info.compute_phase {
+            ComputePhase::Skipped(skipped) => {
+                Some(ExecutionError::ComputePhaseSkipped(skipped.reason))
+            }
+            ComputePhase::Executed(executed) if !executed.success => {
+                Some(ExecutionError::ComputePhaseFailed(ComputeError::new(
+                    executed.exit_code,
+                    executed.exit_arg,
+                    executed.gas_used.into_inner(),
+                    errors,
+                )))
+            }
+            ComputePhase::Executed(_) => match &info.action_phase {
+                Some(action) if !action.success => Some(ExecutionError::ActionPhaseFailed {
+                    result_code: action.result_code,
+                    result_arg: action.result_arg,
+                    name: action_result_name(action.result_code),
+                }),
+                _ => None,
+            },
+        };
+
+        match (error, &info.bounce_phase) {
+            (None, _) => Ok(()),
+            (Some(error), Some(BouncePhase::Executed(_))) => {
+                Err(ExecutionError::Bounced(Box::new(error)))
+            }
+            (Some(error), _) => Err(error),
+        }
+    }
+}
+
+/// Failed compute phase with the decoded exit code.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("{kind} (exit code {exit_code}, exit arg {exit_arg:?}, gas used {gas_used})")]
+pub struct ComputeError {
+    pub kind: ExitCodeKind,
+    pub exit_code: i32,
+    pub exit_arg: Option<i32>,
+    pub gas_used: u64,
+}
+
+impl ComputeError {
+    pub fn new(
+        exit_code: i32,
+        exit_arg: Option<i32>,
+        gas_used: u64,
+        errors: Option<&AbiErrors>,
+    ) -> Self {
+        Self {
+            kind: ExitCodeKind::from_exit_code(exit_code, errors),
+            exit_code,
+            exit_arg,
+            gas_used,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExitCodeKind {
+    /// Standard TVM exception.
+    Tvm(TvmException),
+    OutOfGas,
+    /// Error thrown by the code generated by the TVM Solidity compiler.
+    Solidity(&'static str),
+    /// Code of a failed `require` or `revert`, with the name from the ABI errors.
+    Require(Option<String>),
+    Unknown,
+}
+
+impl ExitCodeKind {
+    pub fn from_exit_code(exit_code: i32, errors: Option<&AbiErrors>) -> Self {
+        if let Some(name) = errors.and_then(|errors| errors.get(exit_code)) {
+            return Self::Require(Some(name.to_owned()));
+        }
+
+        match exit_code {
+            13 | -14 => Self::OutOfGas,
+            code => match TvmException::from_code(code) {
+                Some(exception) => Self::Tvm(exception),
+                None => match solidity_error_name(code) {
+                    Some(name) => Self::Solidity(name),
+                    None if code >= 100 => Self::Require(None),
+                    None => Self::Unknown,
+                },
+            },
+        }
+    }
+}
+
+impl fmt::Display for ExitCodeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tvm(exception) => write!(f, "TVM exception: {}", exception.name()),
+            Self::OutOfGas => f.write_str("out of gas"),
+            Self::Solidity(name) => write!(f, "Solidity runtime error: {name}"),
+            Self::Require(Some(name)) => write!(f, "contract error: {name}"),
+            Self::Require(None) => f.write_str("contract error"),
+            Self::Unknown => f.write_str("unknown error"),
+        }
+    }
+}
+
+/// Standard TVM exceptions, except for the out of gas one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TvmException {
+    StackUnderflow = 2,
+    StackOverflow = 3,
+    IntegerOverflow = 4,
+    RangeCheckError = 5,
+    InvalidOpcode = 6,
+    TypeCheckError = 7,
+    CellOverflow = 8,
+    CellUnderflow = 9,
+    DictionaryError = 10,
+    UnknownError = 11,
+    FatalError = 12,
+    VirtualizationError = 14,
+}
+
+impl TvmException {
+    pub fn from_code(code: i32) -> Option<Self> {
+        Some(match code {
+            2 => Self::StackUnderflow,
+            3 => Self::StackOverflow,
+            4 => Self::IntegerOverflow,
+            5 => Self::RangeCheckError,
+            6 => Self::InvalidOpcode,
+            7 => Self::TypeCheckError,
+            8 => Self::CellOverflow,
+            9 => Self::CellUnderflow,
+            10 => Self::DictionaryError,
+            11 => Self::UnknownError,
+            12 => Self::FatalError,
+            14 => Self::VirtualizationError,
+            _ => return None,
+        })
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::StackUnderflow => "stack underflow",
+            Self::StackOverflow => "stack overflow",
+            Self::IntegerOverflow => "integer overflow",
+            Self::RangeCheckError => "range check error",
+            Self::InvalidOpcode => "invalid opcode",
+            Self::TypeCheckError => "type check error",
+            Self::CellOverflow => "cell overflow",
+            Self::CellUnderflow => "cell underflow",
+            Self::DictionaryError => "dictionary error",
+            Self::UnknownError => "unknown error",
+            Self::FatalError => "fatal error",
+            Self::VirtualizationError => "virtualization error",
+        }
+    }
+}
+
+fn solidity_error_name(code: i32) -> Option<&'static str> {
+    Some(match code {
+        40 => "invalid signature of the external message",
+        50 => "array index is out of range",
+        51 => "constructor has already been called",
+        52 => "replay protection exception",
+        53 => "invalid address to unpack",
+        54 => "pop from an empty array",
+        57 => "external message is expired",
+        58 => "external message has no signature but has a public key",
+        60 => "wrong function id",
+        61 => "deploying state init has no public key",
+        63 => "optional value is not set",
+        64 => "invalid parameters of tvm.buildExtMsg",
+        65 => "call of an unassigned function variable",
+        66 => "string width is less than the number length",
+        67 => "invalid gas to value conversion",
+        68 => "config parameter 20 or 21 is missing",
+        69 => "zero to the power of zero",
+        70 => "substring is out of range",
+        71 => "external function was called by an internal message",
+        72 => "internal function was called by an external message",
+        73 => "value can't be converted to enum",
+        74 => "await answer has a wrong source address",
+        75 => "await answer has a wrong function id",
+        76 => "public function was called before the constructor",
+        77 => "variant can't be converted to the target type",
+        78 => "no private function with the function id",
+        _ => return None,
+    })
+}
+
+fn action_result_name(code: i32) -> &'static str {
+    match code {
+        32 => "invalid action list",
+        33 => "too many actions",
+        34 => "invalid or unsupported action",
+        35 => "invalid source address",
+        36 => "invalid destination address",
+        37 => "not enough value",
+        38 => "not enough extra currencies",
+        40 => "not enough funds to process the message",
+        43 => "message exceeds size limits",
+        _ => "unknown error",
+    }
+}
+
+/// Names of custom exit codes of a contract.
+#[derive(Debug, Default, Clone)]
+pub struct AbiErrors(HashMap<i32, String>);
+
+impl AbiErrors {
+    /// Reads the optional `errors` list of `{ "code", "name" }` objects
+    /// from the ABI JSON.
+    pub fn from_abi_json(abi: &str) -> anyhow::Result<Self> {
+        let abi = serde_json::from_str::<serde_json::Value>(abi).context("invalid ABI")?;
+
+        let mut errors = Self::default();
+        let Some(list) = abi.get("errors") else {
+            return Ok(errors);
+        };
+        for item in list.as_array().context("expected an array of errors")? {
+            let code = item
+                .get("code")
+                .and_then(serde_json::Value::as_i64)
+                .and_then(|code| i32::try_from(code).ok())
+                .context("invalid error code")?;
+            let name = item
+                .get("name")
+                .and_then(serde_json::Value::as_str)
+                .context("invalid error name")?;
+            errors.insert(code, name);
+        }
+        Ok(errors)
+    }
+
+    pub fn insert<T: Into<String>>(&mut self, code: i32, name: T) {
+        self.0.insert(code, name.into());
+    }
+
+    pub fn get(&self, code: i32) -> Option<&str> {
+        self.0.get(&code).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_exit_codes() -> anyhow::Result<()> {
+        let errors = AbiErrors::from_abi_json(
+            r#"{"errors": [{"code": 1001, "name": "NotOwner"}], "functions": []}"#,
+        )?;
+
+        let kind = |code| ExitCodeKind::from_exit_code(code, Some(&errors));
+        assert_eq!(kind(9), ExitCodeKind::Tvm(TvmException::CellUnderflow));
+        assert_eq!(kind(-14), ExitCodeKind::OutOfGas);
+        assert_eq!(
+            kind(52),
+            ExitCodeKind::Solidity("replay protection exception")
+        );
+        assert_eq!(
+            kind(1001),
+            ExitCodeKind::Require(Some("NotOwner".to_owned()))
+        );
+        assert_eq!(kind(1002), ExitCodeKind::Require(None));
+        assert_eq!(kind(30), ExitCodeKind::Unknown);
+
+        let error = ComputeError::new(1001, Some(5), 1000, Some(&errors));
+        assert_eq!(
+            error.to_string(),
+            "contract error: NotOwner (exit code 1001, exit arg Some(5), gas used 1000)"
+        );
+        Ok(())
+    }
+}
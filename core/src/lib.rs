@@ -1,5 +1,5 @@
 pub mod contracts;
-mod error;
+pub mod error;
 pub mod models;
 pub mod transactions;
 pub mod transport;
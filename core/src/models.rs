@@ -50,7 +50,7 @@ pub struct GenTimings {
     pub gen_utime: u32,
 }
 
-#[derive(Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
 pub struct LastTransactionId {
     #[serde(with = "serde_helpers::string")]
     pub lt: u64,
@@ -1,3 +1,5 @@
+mod parsed_transaction;
 mod trace_transaction;
 
-pub use trace_transaction::TraceTransaction;
+pub use parsed_transaction::{ParsedBody, ParsedMessage, ParsedTransaction};
+pub use trace_transaction::{TraceItem, TraceOptions, TraceTransaction};
@@ -0,0 +1,352 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use tycho_types::abi::{AbiHeader, Contract, NamedAbiValue};
+use tycho_types::cell::CellSlice;
+use tycho_types::models::{MsgInfo, OwnedMessage, Transaction};
+use tycho_vm::OwnedCellSlice;
+
+use crate::contracts::events::{ContractExt, DecodedEvent};
+
+/// Prefix of the bounced message body.
+const BOUNCED_PREFIX: u32 = 0xffffffff;
+
+/// Transaction with message bodies decoded using the contract ABI.
+#[derive(Debug, Clone)]
+pub struct ParsedTransaction {
+    /// `None` for transactions without an inbound message, e.g. tick-tock.
+    pub in_msg: Option<ParsedMessage>,
+    pub out_msgs: Vec<ParsedMessage>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParsedMessage {
+    pub message: OwnedMessage,
+    pub body: ParsedBody,
+}
+
+#[derive(Debug, Clone)]
+pub enum ParsedBody {
+    /// Call of a contract function with decoded inputs.
+    Call {
+        function: Arc<str>,
+        values: Vec<NamedAbiValue>,
+    },
+    /// Answer of a function to an external call.
+    Output {
+        function: Arc<str>,
+        values: Vec<NamedAbiValue>,
+    },
+    Event(DecodedEvent),
+    /// Bounced call. Only the function can be identified,
+    /// because the body contains just the beginning of the original one.
+    Bounced {
+        function: Option<Arc<str>>,
+    },
+    /// Body with a known function or event id that failed to decode,
+    /// e.g. produced by a different version of the contract.
+    Invalid {
+        /// Name of the matched function, if any.
+        function: Option<Arc<str>>,
+        error: String,
+    },
+    /// Empty body or unknown function id.
+    Unknown,
+}
+
+impl ParsedTransaction {
+    /// Decodes the inbound message as a call of the contract function
+    /// and outbound messages as outputs, events or outgoing calls.
+    ///
+    /// Outgoing calls are decoded only if they target functions of the same ABI.
+    /// Bodies that fail to decode are kept as [`ParsedBody::Invalid`]
+    /// instead of failing the whole transaction.
+    pub fn parse(tx: &Transaction, contract: &Contract) -> Result<Self> {
+        let in_msg = match &tx.in_msg {
+            Some(cell) => {
+                let message = cell.parse::<OwnedMessage>()?;
+                Some(parse_in_msg(contract, message))
+            }
+            None => None,
+        };
+
+        let mut out_msgs = Vec::new();
+        for item in tx.out_msgs.values() {
+            let message = item?.parse::<OwnedMessage>()?;
+            out_msgs.push(parse_out_msg(contract, message));
+        }
+
+        Ok(Self { in_msg, out_msgs })
+    }
+
+    /// Name of the called function, if the inbound message was recognised.
+    pub fn function(&self) -> Option<&str> {
+        match &self.in_msg.as_ref()?.body {
+            ParsedBody::Call { function, .. } => Some(function),
+            _ => None,
+        }
+    }
+
+    pub fn events(&self) -> impl Iterator<Item = &DecodedEvent> {
+        self.out_msgs.iter().filter_map(|msg| match &msg.body {
+            ParsedBody::Event(event) => Some(event),
+            _ => None,
+        })
+    }
+}
+
+fn parse_in_msg(contract: &Contract, message: OwnedMessage) -> ParsedMessage {
+    let body = match &message.info {
+        MsgInfo::Int(info) if info.bounced => parse_bounced(contract, &message),
+        MsgInfo::Int(_) => parse_internal_call(contract, &message),
+        MsgInfo::ExtIn(_) => parse_external_call(contract, &message),
+        MsgInfo::ExtOut(_) => ParsedBody::Unknown,
+    };
+
+    ParsedMessage { message, body }
+}
+
+fn parse_out_msg(contract: &Contract, message: OwnedMessage) -> ParsedMessage {
+    let body = match &message.info {
+        MsgInfo::Int(info) if info.bounced => parse_bounced(contract, &message),
+        MsgInfo::Int(_) => parse_internal_call(contract, &message),
+        MsgInfo::ExtOut(_) => {
+            let body = OwnedCellSlice::from(message.body.clone());
+            let slice = body.apply();
+            match contract.decode_event(slice) {
+                Ok(Some(event)) => ParsedBody::Event(event),
+                Ok(None) => match slice
+                    .get_u32(0)
+                    .ok()
+                    .and_then(|id| contract.find_function_by_id(id, false))
+                {
+                    Some(function) => match function.decode_output(slice) {
+                        Ok(values) => ParsedBody::Output {
+                            function: function.name.clone(),
+                            values,
+                        },
+                        Err(e) => ParsedBody::Invalid {
+                            function: Some(function.name.clone()),
+                            error: e.to_string(),
+                        },
+                    },
+                    None => ParsedBody::Unknown,
+                },
+                Err(e) => ParsedBody::Invalid {
+                    function: None,
+                    error: e.to_string(),
+                },
+            }
+        }
+        MsgInfo::ExtIn(_) => ParsedBody::Unknown,
+    };
+
+    ParsedMessage { message, body }
+}
+
+fn parse_internal_call(contract: &Contract, message: &OwnedMessage) -> ParsedBody {
+    let body = OwnedCellSlice::from(message.body.clone());
+    let slice = body.apply();
+
+    let Some(function) = slice
+        .get_u32(0)
+        .ok()
+        .and_then(|id| contract.find_function_by_id(id, true))
+    else {
+        return ParsedBody::Unknown;
+    };
+
+    match function.decode_internal_input(slice) {
+        Ok(values) => ParsedBody::Call {
+            function: function.name.clone(),
+            values,
+        },
+        Err(e) => ParsedBody::Invalid {
+            function: Some(function.name.clone()),
+            error: e.to_string(),
+        },
+    }
+}
+
+fn parse_external_call(contract: &Contract, message: &OwnedMessage) -> ParsedBody {
+    let body = OwnedCellSlice::from(message.body.clone());
+    let slice = body.apply();
+
+    let Some(function) =
+        external_function_id(contract, slice).and_then(|id| contract.find_function_by_id(id, true))
+    else {
+        return ParsedBody::Unknown;
+    };
+
+    match function.decode_external_input(slice) {
+        Ok(values) => ParsedBody::Call {
+            function: function.name.clone(),
+            values,
+        },
+        Err(e) => ParsedBody::Invalid {
+            function: Some(function.name.clone()),
+            error: e.to_string(),
+        },
+    }
+}
+
+/// Reads the function id of an external call. Starting with ABI 2.0
+/// it follows the optional signature and the contract headers.
+fn external_function_id(contract: &Contract, mut slice: CellSlice<'_>) -> Option<u32> {
+    if contract.abi_version.major == 1 {
+        return slice.get_u32(0).ok();
+    }
+
+    if slice.load_bit().ok()? {
+        slice.skip_first(512, 0).ok()?;
+    }
+    AbiHeader::skip_all(&contract.headers, &mut slice).ok()?;
+    slice.load_u32().ok()
+}
+
+fn parse_bounced(contract: &Contract, message: &OwnedMessage) -> ParsedBody {
+    let body = OwnedCellSlice::from(message.body.clone());
+    let slice = body.apply();
+
+    if slice.get_u32(0).ok() != Some(BOUNCED_PREFIX) {
+        return ParsedBody::Bounced { function: None };
+    }
+
+    let function = slice
+        .get_u32(32)
+        .ok()
+        .and_then(|id| contract.find_function_by_id(id, true))
+        .map(|function| function.name.clone());
+
+    ParsedBody::Bounced { function }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use tycho_types::abi::AbiValue;
+    use tycho_types::cell::{CellBuilder, HashBytes};
+    use tycho_types::models::{IntAddr, StdAddr};
+    use tycho_types::num::Tokens;
+
+    use super::*;
+    use crate::contracts::blockchain_context::MessageBuilder;
+
+    fn load_contract() -> Result<Contract> {
+        Ok(serde_json::from_str(include_str!("../test/abi.json"))?)
+    }
+
+    fn internal_message(body: tycho_types::cell::Cell, bounced: bool) -> OwnedMessage {
+        let src = IntAddr::Std(StdAddr::new(0, HashBytes::ZERO));
+        let dst = IntAddr::Std(StdAddr::new(0, HashBytes([1; 32])));
+        MessageBuilder::new_internal_in(src, dst)
+            .with_value(Tokens::new(1_000_000_000))
+            .with_bounced(bounced)
+            .with_body(body)
+            .expect("body fits")
+            .build()
+    }
+
+    #[test]
+    fn parse_internal_and_bounced_calls() -> Result<()> {
+        let contract = load_contract()?;
+        let function = contract.functions.get("castVote").expect("function exists");
+
+        let args = [
+            AbiValue::Uint(32, BigUint::from(5u32)).named("proposal_id"),
+            AbiValue::Bool(true).named("support"),
+        ];
+        let body = function.encode_internal_input(&args)?.build()?;
+
+        let parsed = parse_in_msg(&contract, internal_message(body.clone(), false));
+        match parsed.body {
+            ParsedBody::Call { function, values } => {
+                assert_eq!(function.as_ref(), "castVote");
+                assert_eq!(values, args);
+            }
+            body => panic!("unexpected body {body:?}"),
+        }
+
+        let mut bounced = CellBuilder::new();
+        bounced.store_u32(BOUNCED_PREFIX)?;
+        bounced.store_u32(function.input_id)?;
+        let parsed = parse_in_msg(&contract, internal_message(bounced.build()?, true));
+        match parsed.body {
+            ParsedBody::Bounced { function } => {
+                assert_eq!(function.as_deref(), Some("castVote"));
+            }
+            body => panic!("unexpected body {body:?}"),
+        }
+
+        let mut unknown = CellBuilder::new();
+        unknown.store_u32(0)?;
+        let parsed = parse_in_msg(&contract, internal_message(unknown.build()?, false));
+        assert!(matches!(parsed.body, ParsedBody::Unknown));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_external_calls() -> Result<()> {
+        let contract = load_contract()?;
+        let function = contract.functions.get("castVote").expect("function exists");
+        let dst = IntAddr::Std(StdAddr::new(0, HashBytes([1; 32])));
+        let external_message = |body: tycho_types::cell::Cell| -> Result<OwnedMessage> {
+            Ok(MessageBuilder::new_external_in(dst.clone())
+                .with_body(body)?
+                .build())
+        };
+
+        let args = [
+            AbiValue::Uint(32, BigUint::from(5u32)).named("proposal_id"),
+            AbiValue::Bool(true).named("support"),
+        ];
+        let (_, body) = function
+            .encode_external(&args)
+            .with_time(1_700_000_000_000)
+            .with_expire_at(u32::MAX)
+            .build_input_without_signature()?;
+
+        let parsed = parse_in_msg(&contract, external_message(body)?);
+        match parsed.body {
+            ParsedBody::Call { function, values } => {
+                assert_eq!(function.as_ref(), "castVote");
+                assert_eq!(values, args);
+            }
+            body => panic!("unexpected body {body:?}"),
+        }
+
+        // No signature, the `time` and `expire` headers and the function id
+        // without the arguments.
+        let mut truncated = CellBuilder::new();
+        truncated.store_bit_zero()?;
+        truncated.store_u64(1_700_000_000_000)?;
+        truncated.store_u32(u32::MAX)?;
+        truncated.store_u32(function.input_id)?;
+        let parsed = parse_in_msg(&contract, external_message(truncated.build()?)?);
+        match parsed.body {
+            ParsedBody::Invalid { function, .. } => {
+                assert_eq!(function.as_deref(), Some("castVote"));
+            }
+            body => panic!("unexpected body {body:?}"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn malformed_body_is_invalid() -> Result<()> {
+        let contract = load_contract()?;
+        let function = contract.functions.get("castVote").expect("function exists");
+
+        // Known function id without the arguments.
+        let mut truncated = CellBuilder::new();
+        truncated.store_u32(function.input_id)?;
+        let parsed = parse_in_msg(&contract, internal_message(truncated.build()?, false));
+        match parsed.body {
+            ParsedBody::Invalid { function, .. } => {
+                assert_eq!(function.as_deref(), Some("castVote"));
+            }
+            body => panic!("unexpected body {body:?}"),
+        }
+        Ok(())
+    }
+}
@@ -5,41 +5,147 @@ use std::task::{Context, Poll};
 use std::time::Duration;
 
 use anyhow::Result;
-use futures_util::{Future, Stream};
+use futures_util::{Future, Stream, StreamExt};
 use pin_project::pin_project;
 use tokio::sync::Mutex;
+use tokio::time::Instant;
+use tycho_types::abi::Contract;
 use tycho_types::cell::HashBytes;
 use tycho_types::models::{MsgType, OwnedMessage, Transaction};
 use tycho_types::prelude::Load;
 
+use super::ParsedTransaction;
+use crate::contracts::events::{ContractExt, DecodedEvent};
 use crate::transport::Transport;
 
-type NextTransactionFut = Option<Pin<Box<dyn Future<Output = Result<Option<Transaction>>> + Send>>>;
+type NextItemFut = Option<Pin<Box<dyn Future<Output = Result<Option<TraceItem>>> + Send>>>;
 
+/// Item of the transactions tree.
+#[derive(Debug, Clone)]
+pub enum TraceItem {
+    Transaction(Box<Transaction>),
+    /// Internal message whose transaction was not found in time.
+    /// Its subtree is not traced.
+    PendingMessage {
+        message_hash: HashBytes,
+    },
+}
+
+/// Polling parameters of [`TraceTransaction`].
+#[derive(Debug, Clone, Copy)]
+pub struct TraceOptions {
+    /// First delay between requests for the transaction of a message.
+    pub min_interval: Duration,
+    pub max_interval: Duration,
+    /// Multiplier of the delay after each unsuccessful request.
+    pub backoff_factor: u32,
+    /// How long to wait for the transaction of each message.
+    /// Waits until the overall timeout if not set.
+    pub message_timeout: Option<Duration>,
+    /// Time limit for the whole trace. Messages left after it
+    /// are yielded as pending, or the last request error is returned
+    /// if the last request for the message has failed.
+    pub timeout: Option<Duration>,
+    /// Number of consecutive failed requests for the transaction
+    /// of a message after which the trace fails.
+    /// Failed requests are retried until the timeout if not set.
+    pub max_errors: Option<u32>,
+}
+
+impl Default for TraceOptions {
+    fn default() -> Self {
+        Self {
+            min_interval: Duration::from_millis(500),
+            max_interval: Duration::from_millis(3000),
+            backoff_factor: 2,
+            message_timeout: None,
+            timeout: None,
+            max_errors: None,
+        }
+    }
+}
+
+/// Stream of transactions produced by the root transaction.
+///
+/// The stream ends after the first error.
 #[pin_project]
 pub struct TraceTransaction {
     inner: Arc<Mutex<TraceTransactionState>>,
     #[pin]
-    future: NextTransactionFut,
+    future: NextItemFut,
+    finished: bool,
 }
 
 impl TraceTransaction {
     #[allow(unused)]
     pub fn new(root_hash: &HashBytes, transport: Arc<dyn Transport>) -> Self {
+        Self::new_with_options(root_hash, transport, Default::default())
+    }
+
+    pub fn new_with_options(
+        root_hash: &HashBytes,
+        transport: Arc<dyn Transport>,
+        options: TraceOptions,
+    ) -> Self {
         Self {
             inner: Arc::new(Mutex::new(TraceTransactionState {
                 transport,
+                options,
+                deadline: None,
                 yield_root: false,
                 root_hash: Some(*root_hash),
                 queue: Default::default(),
             })),
             future: None,
+            finished: false,
         }
     }
+
+    /// Decodes events from each transaction of the trace.
+    ///
+    /// Transactions without events and pending messages are skipped.
+    pub fn events(
+        self,
+        contract: Arc<Contract>,
+    ) -> impl Stream<Item = Result<(Transaction, Vec<DecodedEvent>)>> {
+        self.filter_map(move |item| {
+            let result = match item {
+                Ok(TraceItem::Transaction(tx)) => match contract.decode_transaction_events(&tx) {
+                    Ok(events) if events.is_empty() => None,
+                    Ok(events) => Some(Ok((*tx, events))),
+                    Err(e) => Some(Err(e)),
+                },
+                Ok(TraceItem::PendingMessage { .. }) => None,
+                Err(e) => Some(Err(e)),
+            };
+            futures_util::future::ready(result)
+        })
+    }
+
+    /// Decodes messages of each transaction of the trace.
+    ///
+    /// Pending messages are skipped.
+    pub fn parsed(
+        self,
+        contract: Arc<Contract>,
+    ) -> impl Stream<Item = Result<(Transaction, ParsedTransaction)>> {
+        self.filter_map(move |item| {
+            let result = match item {
+                Ok(TraceItem::Transaction(tx)) => {
+                    Some(ParsedTransaction::parse(&tx, &contract).map(|parsed| (*tx, parsed)))
+                }
+                Ok(TraceItem::PendingMessage { .. }) => None,
+                Err(e) => Some(Err(e)),
+            };
+            futures_util::future::ready(result)
+        })
+    }
 }
 
 struct TraceTransactionState {
     transport: Arc<dyn Transport>,
+    options: TraceOptions,
+    deadline: Option<Instant>,
     yield_root: bool,
     root_hash: Option<HashBytes>,
     queue: VecDeque<HashBytes>,
@@ -63,10 +169,13 @@ impl TraceTransactionState {
         Ok(())
     }
 
-    async fn next(&mut self) -> Result<Option<Transaction>> {
-        const MIN_INTERVAL_MS: u64 = 500;
-        const MAX_INTERVAL_MS: u64 = 3000;
-        const FACTOR: u64 = 2;
+    async fn next(&mut self) -> Result<Option<TraceItem>> {
+        let options = self.options;
+        let deadline = match (self.deadline, options.timeout) {
+            (Some(deadline), _) => Some(deadline),
+            (None, Some(timeout)) => Some(*self.deadline.insert(Instant::now() + timeout)),
+            (None, None) => None,
+        };
 
         let transport = self.transport.as_ref();
 
@@ -79,36 +188,73 @@ impl TraceTransactionState {
 
             self.root_hash = None;
             if std::mem::take(&mut self.yield_root) {
-                return Ok(Some(tx));
+                return Ok(Some(TraceItem::Transaction(Box::new(tx))));
             }
         }
 
-        let Some(message_hash) = self.queue.front() else {
+        let Some(message_hash) = self.queue.pop_front() else {
             return Ok(None);
         };
 
-        let mut interval_ms = MIN_INTERVAL_MS;
+        let message_deadline = match options.message_timeout {
+            Some(timeout) => {
+                let message_deadline = Instant::now() + timeout;
+                Some(deadline.map_or(message_deadline, |d| d.min(message_deadline)))
+            }
+            None => deadline,
+        };
+
+        let mut interval = options.min_interval;
+        let mut errors = 0;
+        let mut last_error = None;
         let tx = loop {
-            if let Ok(Some(tx)) = transport.get_dst_transaction(message_hash).await {
-                break tx;
+            match transport.get_dst_transaction(&message_hash).await {
+                Ok(Some(tx)) => break tx,
+                Ok(None) => {
+                    errors = 0;
+                    last_error = None;
+                }
+                Err(e) => {
+                    errors += 1;
+                    if options.max_errors.is_some_and(|max| errors >= max) {
+                        return Err(e.context(format!(
+                            "failed to get transaction of message {message_hash}"
+                        )));
+                    }
+                    tracing::debug!("failed to get transaction of message {message_hash}: {e:?}");
+                    last_error = Some(e);
+                }
             }
 
-            tokio::time::sleep(Duration::from_millis(interval_ms)).await;
-            interval_ms = std::cmp::min(interval_ms * FACTOR, MAX_INTERVAL_MS);
+            if let Some(message_deadline) = message_deadline {
+                if Instant::now() + interval >= message_deadline {
+                    return match last_error {
+                        Some(e) => Err(e.context(format!(
+                            "failed to get transaction of message {message_hash}"
+                        ))),
+                        None => Ok(Some(TraceItem::PendingMessage { message_hash })),
+                    };
+                }
+            }
+
+            tokio::time::sleep(interval).await;
+            interval = std::cmp::min(interval * options.backoff_factor, options.max_interval);
         };
 
         Self::extract_messages(&tx, &mut self.queue)?;
-        self.queue.pop_front();
 
-        Ok(Some(tx))
+        Ok(Some(TraceItem::Transaction(Box::new(tx))))
     }
 }
 
 impl Stream for TraceTransaction {
-    type Item = Transaction;
+    type Item = Result<TraceItem>;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let mut this = self.project();
+        if *this.finished {
+            return Poll::Ready(None);
+        }
 
         loop {
             if let Some(fut) = this.future.as_mut().as_pin_mut() {
@@ -117,11 +263,14 @@ impl Stream for TraceTransaction {
                         this.future.set(None);
 
                         match result {
-                            Ok(Some(tx)) => Poll::Ready(Some(tx)),
-                            Ok(None) => Poll::Ready(None), // Stream is done
+                            Ok(Some(item)) => Poll::Ready(Some(Ok(item))),
+                            Ok(None) => {
+                                *this.finished = true;
+                                Poll::Ready(None) // Stream is done
+                            }
                             Err(e) => {
-                                println!("Error in TraceTransaction stream: {}", e);
-                                Poll::Ready(None) //TODO: handle error?
+                                *this.finished = true;
+                                Poll::Ready(Some(Err(e)))
                             }
                         }
                     }
@@ -140,3 +289,187 @@ impl Stream for TraceTransaction {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use futures_util::TryStreamExt;
+    use tycho_types::cell::Cell;
+    use tycho_types::models::{IntAddr, StdAddr};
+
+    use super::*;
+    use crate::contracts::blockchain_context::{BlockchainAccount, MessageBuilder};
+    use crate::contracts::tests::{code_cell, test_account, test_context};
+    use crate::models::{ContractState, LatestBlockchainConfig};
+
+    /// Transport with the root transaction and queued responses
+    /// for its outbound message. Returns `None` after the queue is empty.
+    struct StubTransport {
+        root: Transaction,
+        responses: std::sync::Mutex<VecDeque<Result<Option<Transaction>>>>,
+        requests: AtomicUsize,
+    }
+
+    impl StubTransport {
+        fn new(
+            root: Transaction,
+            responses: impl IntoIterator<Item = Result<Option<Transaction>>>,
+        ) -> Arc<Self> {
+            Arc::new(Self {
+                root,
+                responses: std::sync::Mutex::new(responses.into_iter().collect()),
+                requests: AtomicUsize::new(0),
+            })
+        }
+
+        fn requests(&self) -> usize {
+            self.requests.load(Ordering::Relaxed)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl Transport for StubTransport {
+        async fn send_message(&self, _: &OwnedMessage) -> Result<()> {
+            anyhow::bail!("not supported by the stub transport")
+        }
+
+        async fn send_message_reliable(&self, _: &OwnedMessage) -> Result<Transaction> {
+            anyhow::bail!("not supported by the stub transport")
+        }
+
+        async fn get_contract_state(&self, _: &StdAddr, _: Option<u64>) -> Result<ContractState> {
+            anyhow::bail!("not supported by the stub transport")
+        }
+
+        async fn get_config(&self) -> Result<LatestBlockchainConfig> {
+            anyhow::bail!("not supported by the stub transport")
+        }
+
+        async fn get_transaction(&self, _: &HashBytes) -> Result<Option<Transaction>> {
+            Ok(Some(self.root.clone()))
+        }
+
+        async fn get_dst_transaction(&self, _: &HashBytes) -> Result<Option<Transaction>> {
+            self.requests.fetch_add(1, Ordering::Relaxed);
+            let response = self.responses.lock().unwrap().pop_front();
+            response.unwrap_or(Ok(None))
+        }
+    }
+
+    /// Root transaction with one internal message and the transaction
+    /// of that message.
+    fn transactions() -> Result<(Transaction, Transaction)> {
+        let (context, _) = test_context()?;
+
+        let src = IntAddr::Std(StdAddr::new(0, HashBytes([0x11; 32])));
+        let dst = IntAddr::Std(StdAddr::new(0, HashBytes([0x22; 32])));
+        let out_msg = MessageBuilder::new_internal_in(src.clone(), dst)
+            .with_value(tycho_types::num::Tokens::new(1_000_000_000))
+            .with_bounce(false)
+            .build_cell()?;
+
+        // ACCEPT PUSHREF PUSHINT 1 SENDRAWMSG
+        let code = code_cell(&[0xf8, 0x00, 0x88, 0x71, 0xfb, 0x00], &[out_msg])?;
+        let mut account =
+            BlockchainAccount::new(context.clone(), test_account(code, Cell::empty_cell())?);
+        let message = MessageBuilder::new_external_in(src).build();
+        let root = account.emulate_transaction(&message, true)?.transaction;
+
+        // ACCEPT DROP
+        let code = code_cell(&[0xf8, 0x00, 0x30], &[])?;
+        let mut account = BlockchainAccount::new(context, test_account(code, Cell::empty_cell())?);
+        let child = account.emulate_transaction(&message, true)?.transaction;
+
+        Ok((root, child))
+    }
+
+    fn out_msg_hash(tx: &Transaction) -> Result<HashBytes> {
+        let mut queue = VecDeque::new();
+        TraceTransactionState::extract_messages(tx, &mut queue)?;
+        Ok(queue.pop_front().expect("root has an internal message"))
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retries_with_backoff() -> Result<()> {
+        let (root, child) = transactions()?;
+        let transport = StubTransport::new(
+            root,
+            [
+                Ok(None),
+                Err(anyhow::anyhow!("connection lost")),
+                Ok(Some(child.clone())),
+            ],
+        );
+
+        let started_at = Instant::now();
+        let items = TraceTransaction::new(&HashBytes::ZERO, transport.clone())
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        assert_eq!(items.len(), 1);
+        assert!(matches!(&items[0], TraceItem::Transaction(tx) if tx.lt == child.lt));
+        assert_eq!(transport.requests(), 3);
+        assert_eq!(started_at.elapsed(), Duration::from_millis(500 + 1000));
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn yields_pending_message_after_timeout() -> Result<()> {
+        let (root, _) = transactions()?;
+        let message_hash = out_msg_hash(&root)?;
+        let transport = StubTransport::new(root, []);
+
+        let options = TraceOptions {
+            message_timeout: Some(Duration::from_secs(2)),
+            ..Default::default()
+        };
+        let items = TraceTransaction::new_with_options(&HashBytes::ZERO, transport, options)
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        assert_eq!(items.len(), 1);
+        assert!(matches!(
+            items[0],
+            TraceItem::PendingMessage { message_hash: hash } if hash == message_hash
+        ));
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fails_after_consecutive_errors() -> Result<()> {
+        let (root, _) = transactions()?;
+        let errors = (0..10).map(|_| Err(anyhow::anyhow!("connection lost")));
+        let transport = StubTransport::new(root, errors);
+
+        let options = TraceOptions {
+            max_errors: Some(3),
+            ..Default::default()
+        };
+        let mut trace =
+            TraceTransaction::new_with_options(&HashBytes::ZERO, transport.clone(), options);
+        assert!(trace.next().await.expect("error item").is_err());
+        assert!(trace.next().await.is_none());
+        assert_eq!(transport.requests(), 3);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fails_at_deadline_after_error() -> Result<()> {
+        let (root, _) = transactions()?;
+        let responses = [Ok(None), Err(anyhow::anyhow!("connection lost"))];
+        let transport = StubTransport::new(root, responses);
+
+        // Requests at 0ms and 500ms, the next one would be after the deadline
+        let options = TraceOptions {
+            timeout: Some(Duration::from_millis(1200)),
+            max_errors: None,
+            ..Default::default()
+        };
+        let mut trace =
+            TraceTransaction::new_with_options(&HashBytes::ZERO, transport.clone(), options);
+        assert!(trace.next().await.expect("error item").is_err());
+        assert_eq!(transport.requests(), 2);
+        Ok(())
+    }
+}
@@ -1,8 +1,10 @@
+use crate::contracts::config_cache::ParsedConfigCache;
 use crate::contracts::*;
 use crate::models::{ContractState, LastTransactionId, LatestBlockchainConfig};
 use nekoton_utils::time::{Clock, SimpleClock, Timings};
 use std::collections::HashMap;
-use tycho_executor::{ExecutorParams, ParsedConfig};
+use std::sync::RwLock;
+use tycho_executor::ExecutorParams;
 use tycho_types::cell::HashBytes;
 use tycho_types::models::{
     BlockchainConfig, MsgInfo, OwnedMessage, ShardAccount, StdAddr, Transaction,
@@ -40,9 +42,13 @@ pub trait Connection: Send + Sync {
     async fn update_is_alive_internally(&self);
 }
 
+/// Transport over a fixed set of accounts, e.g. for tests.
+///
+/// Accounts can be replaced to simulate new transactions.
 pub struct SimpleTransport {
-    accounts: HashMap<StdAddr, ShardAccount>,
-    config: BlockchainConfig,
+    accounts: RwLock<HashMap<StdAddr, ShardAccount>>,
+    config: RwLock<LatestBlockchainConfig>,
+    parsed_config: ParsedConfigCache,
 }
 
 impl SimpleTransport {
@@ -65,10 +71,35 @@ impl SimpleTransport {
         }
 
         Ok(SimpleTransport {
-            config,
-            accounts: accs,
+            config: RwLock::new(LatestBlockchainConfig {
+                global_id: 0,
+                seqno: 0,
+                config,
+            }),
+            accounts: RwLock::new(accs),
+            parsed_config: Default::default(),
         })
     }
+
+    /// Replaces the account state.
+    pub fn set_account(&self, address: StdAddr, shard_account: ShardAccount) {
+        self.accounts
+            .write()
+            .unwrap()
+            .insert(address, shard_account);
+    }
+
+    pub fn remove_account(&self, address: &StdAddr) {
+        self.accounts.write().unwrap().remove(address);
+    }
+
+    /// Replaces the config returned by [`get_config`](Transport::get_config)
+    /// as if it was found in the key block with the specified seqno.
+    pub fn set_config(&self, config: BlockchainConfig, seqno: u32) {
+        let mut latest = self.config.write().unwrap();
+        latest.config = config;
+        latest.seqno = seqno;
+    }
 }
 
 #[async_trait::async_trait]
@@ -86,13 +117,19 @@ impl Transport for SimpleTransport {
         let address = dst.as_std().unwrap();
         let account = self
             .accounts
+            .read()
+            .unwrap()
             .get(address)
+            .cloned()
             .ok_or(anyhow::anyhow!("no address found"))?;
 
-        let config = ParsedConfig::parse(self.config.clone(), SimpleClock.now_sec_u64() as u32)?;
+        let config = self.config.read().unwrap().config.clone();
+        let config = self
+            .parsed_config
+            .get(&config, SimpleClock.now_sec_u64() as u32)?;
 
         local_executor::execute_ordinary_transaction(
-            account,
+            &account,
             message,
             &ExecutorParams::default(),
             &config,
@@ -103,11 +140,12 @@ impl Transport for SimpleTransport {
     async fn get_contract_state(
         &self,
         address: &StdAddr,
-        _: Option<u64>,
+        last_transaction_lt: Option<u64>,
     ) -> anyhow::Result<ContractState> {
-        let shard_account = match self.accounts.get(address) {
-            Some(account) => account,
-            None => anyhow::bail!("no account found"),
+        let Some(shard_account) = self.accounts.read().unwrap().get(address).cloned() else {
+            return Ok(ContractState::NotExists {
+                timings: utils::get_gen_timings(&SimpleClock, 0),
+            });
         };
         let timings = utils::get_gen_timings(&SimpleClock, shard_account.last_trans_lt);
 
@@ -116,6 +154,10 @@ impl Transport for SimpleTransport {
             None => return Ok(ContractState::NotExists { timings }),
         };
 
+        if last_transaction_lt == Some(shard_account.last_trans_lt) {
+            return Ok(ContractState::Unchanged { timings });
+        }
+
         Ok(ContractState::Exists {
             account: Box::new(account),
             timings,
@@ -127,11 +169,7 @@ impl Transport for SimpleTransport {
     }
 
     async fn get_config(&self) -> anyhow::Result<LatestBlockchainConfig> {
-        Ok(LatestBlockchainConfig {
-            global_id: 0,
-            seqno: 0,
-            config: self.config.clone(),
-        })
+        Ok(self.config.read().unwrap().clone())
     }
 
     async fn get_transaction(&self, _: &HashBytes) -> anyhow::Result<Option<Transaction>> {
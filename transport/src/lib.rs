@@ -1,5 +1,7 @@
 pub mod options;
 pub mod rpc;
+#[cfg(feature = "ton-lite")]
+pub mod ton_lite;
 
 #[cfg(test)]
 pub mod tests {
@@ -23,7 +25,8 @@ pub mod tests {
 
         let mut traced_tx = TraceTransaction::new(&hash, Arc::new(rpc_transport));
         let mut counter = 0;
-        while traced_tx.next().await.is_some() {
+        while let Some(item) = traced_tx.next().await {
+            item.unwrap();
             counter += 1;
         }
         assert_eq!(counter, 12);
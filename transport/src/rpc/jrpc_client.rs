@@ -5,11 +5,14 @@ use anyhow::Result;
 use nekoton_core::models::{ContractState, LatestBlockchainConfig};
 use nekoton_utils::serde_helpers::*;
 use nekoton_utils::time::Timings;
-use reqwest::Url;
+use reqwest::{header, StatusCode, Url};
 use serde::{Deserialize, Serialize};
 use tycho_types::models::*;
 use tycho_types::prelude::*;
 
+use crate::rpc::rate_limiter::parse_retry_after;
+use crate::rpc::rpc_transport::TransportError;
+
 #[derive(Clone)]
 pub struct JrpcClient {
     client: reqwest::Client,
@@ -29,6 +32,16 @@ impl JrpcClient {
             .send()
             .await?;
 
+        let status = response.status();
+        if status == StatusCode::TOO_MANY_REQUESTS || status == StatusCode::SERVICE_UNAVAILABLE {
+            let retry_after = response
+                .headers()
+                .get(header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(parse_retry_after);
+            return Err(TransportError::Throttled { retry_after }.into());
+        }
+
         let res = response.text().await?;
         match serde_json::from_str(&res)? {
             JrpcResponse::Success(res) => Ok(res),
@@ -1,5 +1,7 @@
 mod jrpc_client;
+mod rate_limiter;
 mod rpc_connection;
 mod rpc_transport;
 
-pub use rpc_transport::{RpcTransport, TransportOptions};
+pub use rate_limiter::RateLimitOptions;
+pub use rpc_transport::{RpcTransport, TransportError, TransportOptions};
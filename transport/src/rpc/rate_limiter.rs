@@ -0,0 +1,116 @@
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tokio::time::Instant;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RateLimitOptions {
+    /// Sustained amount of requests per second allowed for a single endpoint.
+    ///
+    /// Default: `10`
+    pub requests_per_second: u32,
+
+    /// Amount of requests which can be sent at once after an idle period.
+    ///
+    /// Default: `20`
+    pub burst: u32,
+}
+
+impl Default for RateLimitOptions {
+    fn default() -> Self {
+        Self {
+            requests_per_second: 10,
+            burst: 20,
+        }
+    }
+}
+
+/// Token bucket limiter shared between all clones of a connection.
+pub(crate) struct RateLimiter {
+    rate: f64,
+    capacity: f64,
+    state: Mutex<BucketState>,
+}
+
+struct BucketState {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(options: &RateLimitOptions) -> Self {
+        let rate = options.requests_per_second.max(1) as f64;
+        let capacity = options.burst.max(1) as f64;
+
+        Self {
+            rate,
+            capacity,
+            state: Mutex::new(BucketState {
+                tokens: capacity,
+                updated_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Waits until a request can be sent.
+    pub async fn acquire(&self) {
+        while let Err(wait) = self.try_acquire() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Takes a token if available, otherwise returns the time until the next one.
+    pub fn try_acquire(&self) -> Result<(), Duration> {
+        let mut state = self.state.lock();
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.updated_at).as_secs_f64();
+        state.tokens = (state.tokens + elapsed * self.rate).min(self.capacity);
+        state.updated_at = now;
+
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - state.tokens) / self.rate))
+        }
+    }
+}
+
+/// Parses the `Retry-After` header value.
+///
+/// Only the `delay-seconds` form is supported, HTTP dates are ignored.
+pub(crate) fn parse_retry_after(value: &str) -> Option<Duration> {
+    value.trim().parse::<u64>().ok().map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn token_bucket_refills() {
+        let limiter = RateLimiter::new(&RateLimitOptions {
+            requests_per_second: 2,
+            burst: 2,
+        });
+
+        assert!(limiter.try_acquire().is_ok());
+        assert!(limiter.try_acquire().is_ok());
+
+        let wait = limiter.try_acquire().unwrap_err();
+        assert_eq!(wait, Duration::from_millis(500));
+
+        tokio::time::advance(wait).await;
+        assert!(limiter.try_acquire().is_ok());
+        assert!(limiter.try_acquire().is_err());
+    }
+
+    #[test]
+    fn retry_after_seconds() {
+        assert_eq!(parse_retry_after("120"), Some(Duration::from_secs(120)));
+        assert_eq!(parse_retry_after(" 5 "), Some(Duration::from_secs(5)));
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), None);
+    }
+}
@@ -1,5 +1,6 @@
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Result;
 use nekoton_core::models::{ContractState, LatestBlockchainConfig};
@@ -7,16 +8,23 @@ use nekoton_core::transport::Connection;
 use nekoton_utils::time::Timings;
 use parking_lot::Mutex;
 use reqwest::Url;
+use tokio::time::Instant;
 use tycho_types::cell::HashBytes;
 use tycho_types::models::{OwnedMessage, StdAddr, Transaction};
 
 use crate::rpc::jrpc_client;
+use crate::rpc::rate_limiter::RateLimiter;
+use crate::rpc::rpc_transport::{TransportError, TransportOptions};
 
 #[derive(Clone)]
 pub struct RpcConnection {
     is_available: Arc<AtomicBool>,
     rpc_type: RpcType,
     stats: Arc<Mutex<Option<Timings>>>,
+    limiter: Option<Arc<RateLimiter>>,
+    cooldown_until: Arc<Mutex<Option<Instant>>>,
+    throttle_cooldown: Duration,
+    max_throttle_cooldown: Duration,
 }
 
 #[derive(Clone)]
@@ -26,21 +34,71 @@ pub enum RpcType {
 }
 
 impl RpcConnection {
-    pub(crate) fn new(endpoint: Url, client: reqwest::Client, use_proto: bool) -> Self {
-        if !use_proto {
-            Self {
-                is_available: Arc::new(AtomicBool::new(true)),
-                rpc_type: RpcType::Jrpc(jrpc_client::JrpcClient::new(endpoint, client)),
-                stats: Arc::new(Default::default()),
-            }
+    pub(crate) fn new(
+        endpoint: Url,
+        client: reqwest::Client,
+        use_proto: bool,
+        options: &TransportOptions,
+    ) -> Self {
+        let rpc_type = if !use_proto {
+            RpcType::Jrpc(jrpc_client::JrpcClient::new(endpoint, client))
         } else {
-            Self {
-                is_available: Arc::new(AtomicBool::new(true)),
-                rpc_type: RpcType::Proto,
-                stats: Arc::new(Default::default()),
+            RpcType::Proto
+        };
+
+        Self {
+            is_available: Arc::new(AtomicBool::new(true)),
+            rpc_type,
+            stats: Arc::new(Default::default()),
+            limiter: options
+                .rate_limit
+                .as_ref()
+                .map(|options| Arc::new(RateLimiter::new(options))),
+            cooldown_until: Arc::new(Default::default()),
+            throttle_cooldown: options.throttle_cooldown,
+            max_throttle_cooldown: options.max_throttle_cooldown,
+        }
+    }
+
+    /// Waits for the endpoint request budget.
+    pub(crate) async fn acquire(&self) {
+        if let Some(limiter) = &self.limiter {
+            limiter.acquire().await;
+        }
+    }
+
+    /// Temporarily excludes the endpoint from rotation.
+    pub(crate) fn cool_down(&self, duration: Duration) {
+        let until = Instant::now() + duration;
+        let mut cooldown_until = self.cooldown_until.lock();
+        if cooldown_until
+            .map(|current| current < until)
+            .unwrap_or(true)
+        {
+            *cooldown_until = Some(until);
+        }
+    }
+
+    /// Cools the endpoint down after a `429` or `503` response.
+    pub(crate) fn throttle(&self, retry_after: Option<Duration>) {
+        let cooldown = retry_after
+            .unwrap_or(self.throttle_cooldown)
+            .min(self.max_throttle_cooldown);
+        self.cool_down(cooldown);
+    }
+
+    pub(crate) fn is_cooling_down(&self) -> bool {
+        let mut cooldown_until = self.cooldown_until.lock();
+        match *cooldown_until {
+            Some(until) if until > Instant::now() => true,
+            Some(_) => {
+                *cooldown_until = None;
+                false
             }
+            None => false,
         }
     }
+
     pub(crate) async fn send_message(&self, message: &OwnedMessage) -> Result<()> {
         match &self.rpc_type {
             RpcType::Jrpc(client) => client.send_message(message).await,
@@ -177,17 +235,66 @@ impl Connection for RpcConnection {
     }
 
     async fn update_is_alive_internally(&self) {
+        self.acquire().await;
+
         match &self.rpc_type {
             RpcType::Jrpc(client) => match client.get_timings().await {
                 Ok(timings) => {
                     self.force_update_is_alive(true);
                     self.set_stats(Some(timings));
                 }
-                Err(_) => {
-                    self.force_update_is_alive(false);
-                }
+                // A throttling endpoint is alive, it is only skipped until the cooldown ends
+                Err(e) => match e.downcast_ref::<TransportError>() {
+                    Some(TransportError::Throttled { retry_after }) => {
+                        self.throttle(*retry_after);
+                        self.force_update_is_alive(true);
+                    }
+                    _ => self.force_update_is_alive(false),
+                },
             },
             RpcType::Proto => todo!(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    use super::*;
+
+    /// Serves `429 Too Many Requests` to every request.
+    fn spawn_throttling_server() -> Url {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else {
+                    continue;
+                };
+                let mut buffer = [0u8; 4096];
+                let _ = stream.read(&mut buffer);
+                let _ = stream.write_all(
+                    b"HTTP/1.1 429 Too Many Requests\r\nRetry-After: 7\r\n\
+                      Content-Length: 0\r\nConnection: close\r\n\r\n",
+                );
+            }
+        });
+        format!("http://{address}/rpc").parse().unwrap()
+    }
+
+    #[tokio::test]
+    async fn throttled_probe_keeps_endpoint_alive() {
+        let connection = RpcConnection::new(
+            spawn_throttling_server(),
+            reqwest::Client::new(),
+            false,
+            &TransportOptions::default(),
+        );
+
+        connection.update_is_alive_internally().await;
+        assert!(connection.is_alive().await);
+        assert!(connection.is_cooling_down());
+    }
+}
@@ -14,6 +14,7 @@ use tycho_types::models::{OwnedMessage, StdAddr, Transaction};
 use tycho_types::prelude::CellBuilder;
 
 use crate::options::BlockchainOptions;
+use crate::rpc::rate_limiter::RateLimitOptions;
 use crate::rpc::rpc_connection::RpcConnection;
 
 static ROUND_ROBIN_COUNTER: AtomicUsize = AtomicUsize::new(0);
@@ -49,7 +50,7 @@ impl RpcTransport {
 
         let endpoints = endpoints
             .into_iter()
-            .map(|endpoint| RpcConnection::new(endpoint, client.clone(), use_proto))
+            .map(|endpoint| RpcConnection::new(endpoint, client.clone(), use_proto, &options))
             .collect();
 
         let transport = Self {
@@ -88,7 +89,12 @@ impl RpcTransport {
         for _ in 0..self.inner.endpoints.len() {
             let client = {
                 let live_endpoints = self.inner.live_endpoints.read();
-                self.inner.options.choose_strategy.choose(&live_endpoints)
+                let available = live_endpoints
+                    .iter()
+                    .filter(|endpoint| !endpoint.is_cooling_down())
+                    .cloned()
+                    .collect::<Vec<_>>();
+                self.inner.options.choose_strategy.choose(&available)
             };
 
             if client.is_some() {
@@ -114,6 +120,8 @@ impl RpcTransport {
                 .await
                 .ok_or(TransportError::NoEndpointsAvailable)?;
 
+            client.acquire().await;
+
             // TODO: lifetimes to avoid of cloning?
             match f(client.clone()).await {
                 Ok(result) => return Ok(result),
@@ -122,9 +130,7 @@ impl RpcTransport {
                         return Err(e);
                     }
 
-                    let endpoint = client.endpoint();
-                    self.remove_endpoint(endpoint);
-
+                    self.handle_error(&client, &e);
                     tokio::time::sleep(self.inner.options.aggressive_poll_interval).await;
                 }
             }
@@ -133,10 +139,25 @@ impl RpcTransport {
         unreachable!()
     }
 
+    /// Cools a throttling endpoint down, other failed endpoints are removed
+    /// from rotation until the next probe.
+    fn handle_error(&self, client: &RpcConnection, e: &anyhow::Error) {
+        match e.downcast_ref::<TransportError>() {
+            Some(TransportError::Throttled { retry_after }) => client.throttle(*retry_after),
+            _ => self.remove_endpoint(client.endpoint()),
+        }
+    }
+
     async fn update_endpoints(&self) -> usize {
         let mut futures = futures_util::stream::FuturesUnordered::new();
         for endpoint in &self.inner.endpoints {
-            futures.push(async move { endpoint.is_alive().await.then(|| endpoint.clone()) });
+            futures.push(async move {
+                // Probing a cooling endpoint would only extend its throttling
+                if !endpoint.is_cooling_down() {
+                    endpoint.update_is_alive_internally().await;
+                }
+                endpoint.is_alive().await.then(|| endpoint.clone())
+            });
         }
 
         let mut new_endpoints = Vec::with_capacity(self.inner.endpoints.len());
@@ -241,6 +262,22 @@ pub struct TransportOptions {
     ///
     /// Default: `Random`
     pub choose_strategy: ChooseStrategy,
+
+    /// Per-endpoint request budget. Requests wait for a free token when set.
+    ///
+    /// Default: `None`
+    pub rate_limit: Option<RateLimitOptions>,
+
+    /// How long to skip an endpoint which responded with `429` or `503`
+    /// without a `Retry-After` header.
+    ///
+    /// Default: `10 sec`
+    pub throttle_cooldown: Duration,
+
+    /// Upper bound for the cooldown requested by `Retry-After`.
+    ///
+    /// Default: `5 min`
+    pub max_throttle_cooldown: Duration,
 }
 
 impl Default for TransportOptions {
@@ -250,6 +287,9 @@ impl Default for TransportOptions {
             request_timeout: Duration::from_secs(3),
             aggressive_poll_interval: Duration::from_secs(1),
             choose_strategy: ChooseStrategy::Random,
+            rate_limit: None,
+            throttle_cooldown: Duration::from_secs(10),
+            max_throttle_cooldown: Duration::from_secs(300),
         }
     }
 }
@@ -266,6 +306,10 @@ impl ChooseStrategy {
     fn choose(&self, endpoints: &[RpcConnection]) -> Option<RpcConnection> {
         use rand::prelude::SliceRandom;
 
+        if endpoints.is_empty() {
+            return None;
+        }
+
         match self {
             ChooseStrategy::Random => endpoints.choose(&mut rand::thread_rng()).cloned(),
             ChooseStrategy::RoundRobin => {
@@ -286,6 +330,8 @@ pub enum TransportError {
     NoEndpointsAvailable,
     #[error("Message processing timed out")]
     MessageTimeout,
+    #[error("Endpoint is throttling requests")]
+    Throttled { retry_after: Option<Duration> },
 }
 
 #[cfg(test)]
@@ -296,6 +342,60 @@ mod test {
 
     use super::*;
 
+    fn offline_transport(endpoints: &[&str]) -> RpcTransport {
+        let options = TransportOptions::default();
+        let endpoints = endpoints
+            .iter()
+            .map(|url| {
+                RpcConnection::new(
+                    url.parse().unwrap(),
+                    reqwest::Client::new(),
+                    false,
+                    &options,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        RpcTransport {
+            inner: Arc::new(Inner {
+                live_endpoints: RwLock::new(endpoints.clone()),
+                endpoints,
+                options,
+                bc_options: Default::default(),
+            }),
+        }
+    }
+
+    fn live_endpoints(transport: &RpcTransport) -> Vec<String> {
+        let live_endpoints = transport.inner.live_endpoints.read();
+        live_endpoints
+            .iter()
+            .map(|endpoint| endpoint.endpoint().to_owned())
+            .collect()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn throttled_endpoints_cool_down() {
+        let transport = offline_transport(&["http://127.0.0.1:1/rpc", "http://127.0.0.1:2/rpc"]);
+        let first = transport.inner.endpoints[0].clone();
+        let second = transport.inner.endpoints[1].clone();
+
+        let throttled = TransportError::Throttled {
+            retry_after: Some(Duration::from_secs(3600)),
+        };
+        transport.handle_error(&first, &throttled.into());
+        assert!(first.is_cooling_down());
+        assert_eq!(live_endpoints(&transport).len(), 2);
+
+        // Cooldown is clamped by `max_throttle_cooldown`
+        tokio::time::advance(transport.inner.options.max_throttle_cooldown).await;
+        assert!(!first.is_cooling_down());
+
+        transport.handle_error(&second, &anyhow::anyhow!("connection refused"));
+        assert!(!second.is_cooling_down());
+        assert_eq!(live_endpoints(&transport), [first.endpoint().to_owned()]);
+    }
+
     #[tokio::test]
     async fn connection_test() -> Result<()> {
         let endpoints = ["http://57.129.53.62:8080/rpc"]
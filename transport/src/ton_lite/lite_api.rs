@@ -0,0 +1,202 @@
+use anyhow::Result;
+use ton_lite_client::{proto, LiteClient};
+use tycho_types::cell::HashBytes;
+use tycho_types::models::{BlockId, StdAddr};
+
+/// Raw account state with proofs as returned by `liteServer.getAccountState`.
+pub struct RawAccountState {
+    /// Shard block which contains the account state.
+    pub shard_block: BlockId,
+    /// Proof of the shard block description in the masterchain block.
+    /// Empty for masterchain accounts.
+    pub shard_proof: Vec<u8>,
+    pub state: Vec<u8>,
+    pub proof: Vec<u8>,
+}
+
+/// Raw config with proofs as returned by `liteServer.getConfigAll`.
+pub struct RawConfigProof {
+    /// Proof of the block state update.
+    pub state_proof: Vec<u8>,
+    /// Proof of the masterchain state with config.
+    pub config_proof: Vec<u8>,
+}
+
+/// Chain of block links as returned by `liteServer.getBlockProof`.
+pub struct PartialBlockProof {
+    pub complete: bool,
+    pub from: BlockId,
+    pub to: BlockId,
+    pub steps: Vec<BlockLink>,
+}
+
+pub enum BlockLink {
+    Back(BlockLinkBack),
+    Forward(BlockLinkForward),
+}
+
+/// Link from a block to an older masterchain block registered
+/// in its state.
+pub struct BlockLinkBack {
+    pub to_key_block: bool,
+    pub from: BlockId,
+    pub to: BlockId,
+    /// Proof of the target block header.
+    pub dest_proof: Vec<u8>,
+    /// Proof of the source block with its state update.
+    pub proof: Vec<u8>,
+    /// Proof of the source block state with previous blocks.
+    pub state_proof: Vec<u8>,
+}
+
+/// Link from a key block to a newer block signed by the validators
+/// of the key block.
+pub struct BlockLinkForward {
+    pub to_key_block: bool,
+    pub from: BlockId,
+    pub to: BlockId,
+    /// Proof of the target block header.
+    pub dest_proof: Vec<u8>,
+    /// Proof of the source key block config.
+    pub config_proof: Vec<u8>,
+    pub validator_set_hash: u32,
+    pub catchain_seqno: u32,
+    pub signatures: Vec<BlockSignature>,
+}
+
+pub struct BlockSignature {
+    pub node_id_short: HashBytes,
+    pub signature: Vec<u8>,
+}
+
+/// Subset of the lite-server API used by [`TonLiteTransport`].
+///
+/// Implemented for [`LiteClient`], can be replaced with a stand-in
+/// to serve canned responses.
+///
+/// [`TonLiteTransport`]: super::TonLiteTransport
+#[async_trait::async_trait]
+pub trait LiteApi: Send + Sync {
+    async fn get_last_mc_block_id(&self) -> Result<BlockId>;
+
+    async fn get_account_state(
+        &self,
+        block: &BlockId,
+        address: &StdAddr,
+    ) -> Result<RawAccountState>;
+
+    async fn get_config(&self, block: &BlockId) -> Result<RawConfigProof>;
+
+    /// Returns a chain of links from the known block to the target block
+    /// (or to the latest one if not specified).
+    async fn get_block_proof(
+        &self,
+        known_block: &BlockId,
+        target_block: Option<&BlockId>,
+    ) -> Result<PartialBlockProof>;
+
+    /// Returns a BOC with up to `count` account transactions starting from
+    /// the specified one and going back in time.
+    async fn get_transactions(
+        &self,
+        address: &StdAddr,
+        lt: u64,
+        hash: &HashBytes,
+        count: u8,
+    ) -> Result<Vec<u8>>;
+
+    async fn send_message(&self, message: Vec<u8>) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl LiteApi for LiteClient {
+    async fn get_last_mc_block_id(&self) -> Result<BlockId> {
+        LiteClient::get_last_mc_block_id(self).await
+    }
+
+    async fn get_account_state(
+        &self,
+        block: &BlockId,
+        address: &StdAddr,
+    ) -> Result<RawAccountState> {
+        let account = self.get_account(block, address).await?;
+        Ok(RawAccountState {
+            shard_block: account.shardblk,
+            shard_proof: account.shard_proof,
+            state: account.state,
+            proof: account.proof,
+        })
+    }
+
+    async fn get_config(&self, block: &BlockId) -> Result<RawConfigProof> {
+        let config = LiteClient::get_config(self, block).await?;
+        Ok(RawConfigProof {
+            state_proof: config.state_proof,
+            config_proof: config.config_proof,
+        })
+    }
+
+    async fn get_block_proof(
+        &self,
+        known_block: &BlockId,
+        target_block: Option<&BlockId>,
+    ) -> Result<PartialBlockProof> {
+        let proof = LiteClient::get_block_proof(self, known_block, target_block).await?;
+
+        let steps = proof
+            .steps
+            .into_iter()
+            .map(|step| match step {
+                proto::BlockLink::BlockLinkBack(link) => BlockLink::Back(BlockLinkBack {
+                    to_key_block: link.to_key_block,
+                    from: link.from,
+                    to: link.to,
+                    dest_proof: link.dest_proof,
+                    proof: link.proof,
+                    state_proof: link.state_proof,
+                }),
+                proto::BlockLink::BlockLinkForward(link) => BlockLink::Forward(BlockLinkForward {
+                    to_key_block: link.to_key_block,
+                    from: link.from,
+                    to: link.to,
+                    dest_proof: link.dest_proof,
+                    config_proof: link.config_proof,
+                    validator_set_hash: link.signatures.validator_set_hash,
+                    catchain_seqno: link.signatures.catchain_seqno,
+                    signatures: link
+                        .signatures
+                        .signatures
+                        .into_iter()
+                        .map(|item| BlockSignature {
+                            node_id_short: item.node_id_short,
+                            signature: item.signature,
+                        })
+                        .collect(),
+                }),
+            })
+            .collect();
+
+        Ok(PartialBlockProof {
+            complete: proof.complete,
+            from: proof.from,
+            to: proof.to,
+            steps,
+        })
+    }
+
+    async fn get_transactions(
+        &self,
+        address: &StdAddr,
+        lt: u64,
+        hash: &HashBytes,
+        count: u8,
+    ) -> Result<Vec<u8>> {
+        let list = LiteClient::get_transactions(self, address, lt, hash, count).await?;
+        Ok(list.transactions)
+    }
+
+    async fn send_message(&self, message: Vec<u8>) -> Result<()> {
+        LiteClient::send_message(self, message).await?;
+        Ok(())
+    }
+}
@@ -1,4 +1,11 @@
+mod lite_api;
 mod models;
+mod proofs;
 mod ton_lite_transport;
+mod tx_cache;
 
-pub use ton_lite_transport::TonLiteTransport;
+pub use lite_api::{
+    BlockLink, BlockLinkForward, BlockSignature, LiteApi, PartialBlockProof, RawAccountState,
+    RawConfigProof,
+};
+pub use ton_lite_transport::{TonLiteTransport, TonLiteTransportOptions};
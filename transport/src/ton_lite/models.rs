@@ -1,9 +1,9 @@
 use anyhow::Context;
-use tycho_types::cell::{Cell, CellSlice, HashBytes, Load};
+use nekoton_core::models::{GenTimings, LastTransactionId};
+use tycho_types::cell::{Cell, CellSlice, DynCell, HashBytes, Load};
 use tycho_types::dict::Dict;
 use tycho_types::error::Error;
-use tycho_types::models::{BlockchainConfig, CurrencyCollection, ShardAccounts, ShardHashes};
-use nekoton_core::models::{GenTimings, LastTransactionId};
+use tycho_types::models::{BlockchainConfig, CurrencyCollection, ShardAccounts};
 
 pub(crate) struct ParsedProofs {
     pub timings: GenTimings,
@@ -15,6 +15,32 @@ impl ParsedProofs {
         &self,
         account: &HashBytes,
     ) -> anyhow::Result<LastTransactionId> {
+        let Some(state) = self.find_account(account)? else {
+            anyhow::bail!("account state not found");
+        };
+
+        Ok(LastTransactionId {
+            hash: state.last_trans_hash,
+            lt: state.last_trans_lt,
+        })
+    }
+
+    /// Checks that the received account state is the one from the state proof.
+    pub(crate) fn check_account_state(
+        &self,
+        account: &HashBytes,
+        state: Option<&DynCell>,
+    ) -> anyhow::Result<()> {
+        let valid = match (self.find_account(account)?, state) {
+            (Some(proof), Some(state)) => proof.account_hash == *state.repr_hash(),
+            (None, None) => true,
+            _ => false,
+        };
+        anyhow::ensure!(valid, "account state does not match the state proof");
+        Ok(())
+    }
+
+    fn find_account(&self, account: &HashBytes) -> anyhow::Result<Option<TonShardAccountShort>> {
         type ShardAccountsShort = Dict<HashBytes, TonShardAccountShort>;
 
         let proof = self
@@ -28,22 +54,17 @@ impl ParsedProofs {
             .context("failed to parse shard accounts")?;
         let accounts = ShardAccountsShort::from_raw(accounts.dict().root().clone());
 
-        let Some(state) = accounts.get(account).context("failed to get tx id")? else {
-            anyhow::bail!("account state not found");
-        };
-
-        Ok(LastTransactionId {
-            hash: state.last_trans_hash,
-            lt: state.last_trans_lt,
-        })
+        accounts.get(account).context("failed to get account state")
     }
 }
 
 #[derive(Load)]
 #[tlb(tag = "#cc26")]
 pub(crate) struct TonMcStateExtraShort {
-    _shard_hashes: ShardHashes,
+    pub shard_hashes: Dict<i32, Cell>,
     pub config: BlockchainConfig,
+    /// Cell with `validator_info`, `prev_blocks` and other fields.
+    pub info: Cell,
 }
 
 #[derive(Load)]
@@ -54,6 +75,7 @@ pub(crate) struct TonShardStateShort {
 }
 
 pub(crate) struct TonShardAccountShort {
+    account_hash: HashBytes,
     last_trans_hash: HashBytes,
     last_trans_lt: u64,
 }
@@ -64,10 +86,11 @@ impl<'a> Load<'a> for TonShardAccountShort {
         slice.skip_first(5, 0)?;
         // Skip balance.
         _ = CurrencyCollection::load_from(slice)?;
-        // Skip account.
-        Cell::load_from(slice)?;
+        // Account cell can be pruned, so only its hash is used.
+        let account_hash = *Cell::load_from(slice)?.hash(0);
 
         Ok(Self {
+            account_hash,
             last_trans_hash: slice.load_u256()?,
             last_trans_lt: slice.load_u64()?,
         })
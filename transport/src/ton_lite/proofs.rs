@@ -0,0 +1,727 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{Context, Result};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use sha2::{Digest, Sha256};
+use tycho_types::boc::de::{BocHeader, Options};
+use tycho_types::boc::Boc;
+use tycho_types::cell::{Cell, CellFamily, DynCell, HashBytes, Load};
+use tycho_types::dict::AugDict;
+use tycho_types::merkle::{MerkleProof, MerkleUpdate};
+use tycho_types::models::{
+    BlockId, BlockchainConfig, ConfigParam28, ConfigParam34, CurrencyCollection, KeyBlockRef,
+    KeyMaxLt,
+};
+
+use crate::ton_lite::lite_api::{BlockLinkBack, BlockLinkForward};
+use crate::ton_lite::models::TonMcStateExtraShort;
+
+const BLOCK_INFO_TAG: u32 = 0x9bc7a987;
+const BLOCK_EXTRA_TAG: u32 = 0x4a33f6fd;
+const MC_BLOCK_EXTRA_TAG: u16 = 0xcca5;
+
+/// TL id of `ton.blockId root_cell_hash:int256 file_hash:int256`.
+const TL_BLOCK_ID: u32 = 0xc50b6e70;
+/// TL id of `pub.ed25519 key:int256`.
+const TL_PUB_ED25519: u32 = 0x4813b4c6;
+
+/// Decodes a BOC with exactly `count` roots.
+pub(crate) fn decode_roots(data: &[u8], count: usize) -> Result<Vec<Cell>> {
+    let header = BocHeader::decode(
+        data,
+        &Options {
+            max_roots: Some(count),
+            min_roots: Some(count),
+        },
+    )?;
+
+    let roots = header.roots().to_vec();
+    let cells = header.finalize(Cell::empty_context())?;
+
+    roots
+        .into_iter()
+        .map(|id| cells.get(id).context("proof root not found"))
+        .collect()
+}
+
+/// Checks the block proof against the block id and returns the block root.
+pub(crate) fn check_block_proof(proof: &DynCell, block_id: &BlockId) -> Result<Cell> {
+    let proof = proof.parse_exotic::<MerkleProof>()?;
+    anyhow::ensure!(
+        proof.hash == block_id.root_hash && proof.cell.hash(0) == &block_id.root_hash,
+        "block proof hash mismatch for block {block_id}"
+    );
+    Ok(proof.cell)
+}
+
+/// Checks the state proof against the state update of the block
+/// and returns the state root.
+pub(crate) fn check_state_proof(block_root: &DynCell, proof: &DynCell) -> Result<Cell> {
+    let state_update = block_root
+        .reference(2)
+        .context("state update not found")?
+        .parse_exotic::<MerkleUpdate>()?;
+
+    let proof = proof.parse_exotic::<MerkleProof>()?;
+    anyhow::ensure!(
+        proof.hash == state_update.new_hash && proof.cell.hash(0) == &state_update.new_hash,
+        "state proof hash mismatch"
+    );
+    Ok(proof.cell)
+}
+
+/// Checks that the shard block is registered in the masterchain block.
+pub(crate) fn check_shard_proof(
+    mc_block: &BlockId,
+    shard_block: &BlockId,
+    shard_proof: &[u8],
+) -> Result<()> {
+    let roots = decode_roots(shard_proof, 2)?;
+    let mc_block_root = check_block_proof(roots[0].as_ref(), mc_block)?;
+    let mc_state_root = check_state_proof(mc_block_root.as_ref(), roots[1].as_ref())?;
+
+    let extra = load_mc_state_extra(mc_state_root.as_ref())?;
+    let shards = extra
+        .shard_hashes
+        .get(shard_block.shard.workchain())?
+        .context("workchain shards not found")?;
+
+    anyhow::ensure!(
+        contains_shard_block(shards.as_ref(), shard_block)?,
+        "shard block {shard_block} is not registered in {mc_block}"
+    );
+    Ok(())
+}
+
+pub(crate) fn load_mc_state_extra(state_root: &DynCell) -> Result<TonMcStateExtraShort> {
+    let mut cs = state_root.as_slice()?;
+    cs.only_last(1, 1)?;
+    let extra = <Option<Cell>>::load_from(&mut cs)
+        .context("failed to read McStateExtra")?
+        .context("expected McStateExtra")?
+        .parse::<TonMcStateExtraShort>()?;
+    Ok(extra)
+}
+
+/// Checks a forward link signed by the validators of the source key block.
+pub(crate) fn check_forward_link(link: &BlockLinkForward) -> Result<()> {
+    let dest_proof = Boc::decode(&link.dest_proof)?;
+    let dest_root = check_block_proof(dest_proof.as_ref(), &link.to)?;
+    anyhow::ensure!(
+        is_key_block(dest_root.as_ref())? == link.to_key_block,
+        "key block flag mismatch for block {}",
+        link.to
+    );
+
+    let config_proof = Boc::decode(&link.config_proof)?;
+    let config_root = check_block_proof(config_proof.as_ref(), &link.from)?;
+    let config = load_key_block_config(config_root.as_ref())?;
+
+    check_block_signatures(&config, link)
+}
+
+/// Checks a backward link, the target block must be registered
+/// in the state of the source block.
+pub(crate) fn check_backward_link(link: &BlockLinkBack) -> Result<()> {
+    let dest_proof = Boc::decode(&link.dest_proof)?;
+    let dest_root = check_block_proof(dest_proof.as_ref(), &link.to)?;
+    anyhow::ensure!(
+        is_key_block(dest_root.as_ref())? == link.to_key_block,
+        "key block flag mismatch for block {}",
+        link.to
+    );
+
+    let proof = Boc::decode(&link.proof)?;
+    let block_root = check_block_proof(proof.as_ref(), &link.from)?;
+    let state_proof = Boc::decode(&link.state_proof)?;
+    let state_root = check_state_proof(block_root.as_ref(), state_proof.as_ref())?;
+
+    let extra = load_mc_state_extra(state_root.as_ref())?;
+    let (_, prev_block) = load_prev_blocks(extra.info.as_ref())?
+        .get(link.to.seqno)?
+        .with_context(|| format!("block {} is not registered in {}", link.to, link.from))?;
+
+    let block_ref = &prev_block.block_ref;
+    anyhow::ensure!(
+        block_ref.root_hash == link.to.root_hash
+            && block_ref.file_hash == link.to.file_hash
+            && prev_block.is_key_block == link.to_key_block,
+        "block {} does not match the one registered in {}",
+        link.to,
+        link.from
+    );
+    Ok(())
+}
+
+fn load_prev_blocks(info: &DynCell) -> Result<AugDict<u32, KeyMaxLt, KeyBlockRef>> {
+    let mut cs = info.as_slice()?;
+    // Skip `flags` and `validator_info`.
+    cs.skip_first(16 + 65, 0)?;
+    Ok(AugDict::load_from(&mut cs)?)
+}
+
+fn check_block_signatures(config: &BlockchainConfig, link: &BlockLinkForward) -> Result<()> {
+    let validator_set = config
+        .get::<ConfigParam34>()?
+        .context("current validator set not found")?;
+    let catchain_config = config
+        .get::<ConfigParam28>()?
+        .context("catchain config not found")?;
+
+    let (subset, hash_short) = validator_set
+        .compute_mc_subset(link.catchain_seqno, catchain_config.shuffle_mc_validators)
+        .context("failed to compute validator subset")?;
+    anyhow::ensure!(
+        hash_short == link.validator_set_hash,
+        "validator set hash mismatch"
+    );
+
+    let validators = subset
+        .iter()
+        .map(|item| (node_id_short(&item.public_key), item))
+        .collect::<HashMap<_, _>>();
+
+    let data = block_signing_data(&link.to);
+
+    let mut seen = HashSet::new();
+    let mut signed_weight = 0u128;
+    for item in &link.signatures {
+        let validator = validators
+            .get(&item.node_id_short)
+            .context("block is signed by an unknown validator")?;
+        anyhow::ensure!(seen.insert(item.node_id_short), "duplicate block signature");
+
+        let key = VerifyingKey::from_bytes(&validator.public_key.0)?;
+        let signature = Signature::from_slice(&item.signature)?;
+        key.verify(&data, &signature)
+            .context("invalid block signature")?;
+
+        signed_weight += validator.weight as u128;
+    }
+
+    let total_weight = subset.iter().map(|item| item.weight as u128).sum::<u128>();
+    anyhow::ensure!(
+        signed_weight * 3 > total_weight * 2,
+        "not enough signatures for block {}",
+        link.to
+    );
+    Ok(())
+}
+
+/// Data signed by validators, the boxed `ton.blockId` of the block.
+fn block_signing_data(block_id: &BlockId) -> Vec<u8> {
+    let mut data = Vec::with_capacity(4 + 32 + 32);
+    data.extend_from_slice(&TL_BLOCK_ID.to_le_bytes());
+    data.extend_from_slice(block_id.root_hash.as_slice());
+    data.extend_from_slice(block_id.file_hash.as_slice());
+    data
+}
+
+fn node_id_short(public_key: &HashBytes) -> HashBytes {
+    let mut hasher = Sha256::new();
+    hasher.update(TL_PUB_ED25519.to_le_bytes());
+    hasher.update(public_key.as_slice());
+    HashBytes(hasher.finalize().into())
+}
+
+fn is_key_block(block_root: &DynCell) -> Result<bool> {
+    let mut cs = block_root
+        .reference(0)
+        .context("block info not found")?
+        .as_slice()?;
+    anyhow::ensure!(cs.load_u32()? == BLOCK_INFO_TAG, "invalid block info");
+
+    // Skip `version`, `not_master`, `after_merge`, `before_split`,
+    // `after_split`, `want_split` and `want_merge`.
+    cs.skip_first(32 + 6, 0)?;
+    Ok(cs.load_bit()?)
+}
+
+fn load_key_block_config(block_root: &DynCell) -> Result<BlockchainConfig> {
+    let mut cs = block_root
+        .reference(3)
+        .context("block extra not found")?
+        .as_slice()?;
+    anyhow::ensure!(cs.load_u32()? == BLOCK_EXTRA_TAG, "invalid block extra");
+
+    // Skip `rand_seed`, `created_by`, `in_msg_descr`, `out_msg_descr`
+    // and `account_blocks`.
+    cs.skip_first(256 + 256, 3)?;
+    let custom = <Option<Cell>>::load_from(&mut cs)?.context("not a masterchain block")?;
+
+    let mut cs = custom.as_slice()?;
+    anyhow::ensure!(
+        cs.load_u16()? == MC_BLOCK_EXTRA_TAG,
+        "invalid masterchain block extra"
+    );
+    anyhow::ensure!(cs.load_bit()?, "not a key block");
+
+    // Skip `shard_hashes`.
+    _ = <Option<Cell>>::load_from(&mut cs)?;
+    // Skip `shard_fees` with its `fees` and `create` extra.
+    _ = <Option<Cell>>::load_from(&mut cs)?;
+    _ = CurrencyCollection::load_from(&mut cs)?;
+    _ = CurrencyCollection::load_from(&mut cs)?;
+    // Skip `prev_blk_signatures`, `recover_create_msg` and `mint_msg`.
+    cs.skip_first(0, 1)?;
+
+    Ok(BlockchainConfig::load_from(&mut cs)?)
+}
+
+fn contains_shard_block(root: &DynCell, shard_block: &BlockId) -> Result<bool> {
+    let mut cs = root.as_slice()?;
+    if cs.load_bit()? {
+        // bt_fork$1 left:^(BinTree X) right:^(BinTree X)
+        for child in [cs.load_reference()?, cs.load_reference()?] {
+            // Other shards are pruned from the proof.
+            if child.is_exotic() {
+                continue;
+            }
+            if contains_shard_block(child, shard_block)? {
+                return Ok(true);
+            }
+        }
+        return Ok(false);
+    }
+
+    // bt_leaf$0 leaf:ShardDescr
+    let tag = cs.load_small_uint(4)?;
+    anyhow::ensure!(matches!(tag, 0xa | 0xb), "invalid shard description");
+
+    let seqno = cs.load_u32()?;
+    // Skip `reg_mc_seqno`, `start_lt` and `end_lt`.
+    cs.skip_first(32 + 64 + 64, 0)?;
+    let root_hash = cs.load_u256()?;
+    let file_hash = cs.load_u256()?;
+
+    Ok(seqno == shard_block.seqno
+        && root_hash == shard_block.root_hash
+        && file_hash == shard_block.file_hash)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::num::NonZeroU16;
+
+    use ed25519_dalek::{Signer, SigningKey};
+    use tycho_types::cell::{CellBuilder, Store};
+    use tycho_types::models::{
+        BlockRef, CatchainConfig, ShardIdent, ValidatorDescription, ValidatorSet,
+    };
+
+    use super::*;
+    use crate::ton_lite::lite_api::BlockSignature;
+
+    const CATCHAIN_SEQNO: u32 = 7;
+
+    pub(crate) fn validator_keys() -> Vec<SigningKey> {
+        (1..=4u8)
+            .map(|i| SigningKey::from_bytes(&[i; 32]))
+            .collect()
+    }
+
+    pub(crate) fn key_block_config(keys: &[SigningKey]) -> Result<BlockchainConfig> {
+        let mut config = BlockchainConfig::new_empty(HashBytes([0x55; 32]));
+        config.set::<ConfigParam28>(&CatchainConfig {
+            isolate_mc_validators: false,
+            shuffle_mc_validators: true,
+            mc_catchain_lifetime: 250,
+            shard_catchain_lifetime: 250,
+            shard_validators_lifetime: 1000,
+            shard_validators_num: 4,
+        })?;
+        config.set::<ConfigParam34>(&ValidatorSet {
+            utime_since: 0,
+            utime_until: u32::MAX,
+            main: NonZeroU16::new(keys.len() as u16).unwrap(),
+            total_weight: 10 * keys.len() as u64,
+            list: keys
+                .iter()
+                .map(|key| ValidatorDescription {
+                    public_key: HashBytes(key.verifying_key().to_bytes()),
+                    weight: 10,
+                    adnl_addr: None,
+                    mc_seqno_since: 0,
+                    prev_total_weight: 0,
+                })
+                .collect(),
+        })?;
+        Ok(config)
+    }
+
+    /// Builds a block with only the fields read by the proof checks.
+    pub(crate) fn build_block(key_block: bool, config: Option<&BlockchainConfig>) -> Result<Cell> {
+        build_block_with_state(key_block, config, None)
+    }
+
+    /// Builds a block with a state update to the specified state.
+    pub(crate) fn build_block_with_state(
+        key_block: bool,
+        config: Option<&BlockchainConfig>,
+        state: Option<&Cell>,
+    ) -> Result<Cell> {
+        let mut info = CellBuilder::new();
+        info.store_u32(BLOCK_INFO_TAG)?;
+        info.store_u32(0)?;
+        info.store_zeros(6)?;
+        info.store_bit(key_block)?;
+
+        let mut extra = CellBuilder::new();
+        extra.store_u32(BLOCK_EXTRA_TAG)?;
+        extra.store_zeros(256 + 256)?;
+        for _ in 0..3 {
+            extra.store_reference(Cell::empty_cell())?;
+        }
+        match config {
+            Some(config) => {
+                let mut custom = CellBuilder::new();
+                custom.store_u16(MC_BLOCK_EXTRA_TAG)?;
+                custom.store_bit_one()?;
+                custom.store_zeros(2)?;
+                CurrencyCollection::ZERO.store_into(&mut custom, Cell::empty_context())?;
+                CurrencyCollection::ZERO.store_into(&mut custom, Cell::empty_context())?;
+                custom.store_reference(Cell::empty_cell())?;
+                config.store_into(&mut custom, Cell::empty_context())?;
+
+                extra.store_bit_one()?;
+                extra.store_reference(custom.build()?)?;
+            }
+            None => extra.store_bit_zero()?,
+        }
+
+        let mut block = CellBuilder::new();
+        block.store_u32(0x11ef55aa)?;
+        block.store_reference(info.build()?)?;
+        block.store_reference(Cell::empty_cell())?;
+        match state {
+            Some(state) => {
+                let update = MerkleUpdate {
+                    old_hash: *state.repr_hash(),
+                    new_hash: *state.repr_hash(),
+                    old_depth: state.repr_depth(),
+                    new_depth: state.repr_depth(),
+                    old: state.clone(),
+                    new: state.clone(),
+                };
+                block.store_reference(CellBuilder::build_from(update)?)?;
+            }
+            None => block.store_reference(Cell::empty_cell())?,
+        }
+        block.store_reference(extra.build()?)?;
+        Ok(block.build()?)
+    }
+
+    pub(crate) fn block_id(block: &Cell, seqno: u32) -> BlockId {
+        BlockId {
+            shard: ShardIdent::MASTERCHAIN,
+            seqno,
+            root_hash: *block.repr_hash(),
+            file_hash: HashBytes([seqno as u8; 32]),
+        }
+    }
+
+    /// Encodes a proof which keeps all cells of the block.
+    pub(crate) fn full_proof(block: &Cell) -> Result<Vec<u8>> {
+        fn collect(cell: &DynCell, hashes: &mut HashSet<HashBytes>) {
+            hashes.insert(*cell.repr_hash());
+            for child in cell.references() {
+                collect(child, hashes);
+            }
+        }
+
+        let mut hashes = HashSet::new();
+        collect(block.as_ref(), &mut hashes);
+        let proof = MerkleProof::create(block.as_ref(), hashes).build()?;
+        Ok(Boc::encode(CellBuilder::build_from(proof)?))
+    }
+
+    /// Forward link from a key block built with [`key_block_config`]
+    /// to the specified block, signed by the specified validators.
+    pub(crate) fn signed_link(
+        (from_block, from_seqno): (&Cell, u32),
+        (to_block, to_seqno): (&Cell, u32),
+        to_key_block: bool,
+        signers: &[SigningKey],
+    ) -> Result<BlockLinkForward> {
+        let config = key_block_config(&validator_keys())?;
+        let from = block_id(from_block, from_seqno);
+        let to = block_id(to_block, to_seqno);
+
+        let validator_set = config.get::<ConfigParam34>()?.unwrap();
+        let (_, validator_set_hash) = validator_set
+            .compute_mc_subset(CATCHAIN_SEQNO, true)
+            .unwrap();
+
+        let data = block_signing_data(&to);
+
+        let signatures = signers
+            .iter()
+            .map(|key| BlockSignature {
+                node_id_short: node_id_short(&HashBytes(key.verifying_key().to_bytes())),
+                signature: key.sign(&data).to_bytes().to_vec(),
+            })
+            .collect();
+
+        Ok(BlockLinkForward {
+            to_key_block,
+            from,
+            to,
+            dest_proof: full_proof(to_block)?,
+            config_proof: full_proof(from_block)?,
+            validator_set_hash,
+            catchain_seqno: CATCHAIN_SEQNO,
+            signatures,
+        })
+    }
+
+    /// Forward link from a key block to the next block, signed by
+    /// the specified validators.
+    fn forward_link(signers: &[SigningKey]) -> Result<BlockLinkForward> {
+        let config = key_block_config(&validator_keys())?;
+        let key_block = build_block(true, Some(&config))?;
+        let next_block = build_block(false, None)?;
+        signed_link((&key_block, 10), (&next_block, 11), false, signers)
+    }
+
+    /// Masterchain state with the specified previous blocks.
+    fn mc_state(prev_blocks: &[(BlockId, bool)]) -> Result<Cell> {
+        let context = Cell::empty_context();
+
+        let mut prev = AugDict::<u32, KeyMaxLt, KeyBlockRef>::new();
+        for (block_id, is_key_block) in prev_blocks {
+            prev.set(
+                block_id.seqno,
+                KeyMaxLt {
+                    has_key_block: *is_key_block,
+                    max_end_lt: 0,
+                },
+                KeyBlockRef {
+                    is_key_block: *is_key_block,
+                    block_ref: BlockRef {
+                        end_lt: 0,
+                        seqno: block_id.seqno,
+                        root_hash: block_id.root_hash,
+                        file_hash: block_id.file_hash,
+                    },
+                },
+            )?;
+        }
+
+        let mut info = CellBuilder::new();
+        info.store_u16(0)?;
+        info.store_zeros(65)?;
+        prev.store_into(&mut info, context)?;
+        // `after_key_block` and `last_key_block`
+        info.store_zeros(2)?;
+
+        let mut extra = CellBuilder::new();
+        extra.store_u16(0xcc26)?;
+        extra.store_bit_zero()?;
+        BlockchainConfig::new_empty(HashBytes([0x55; 32])).store_into(&mut extra, context)?;
+        extra.store_reference(info.build()?)?;
+        CurrencyCollection::ZERO.store_into(&mut extra, context)?;
+
+        let mut state = CellBuilder::new();
+        state.store_bit_one()?;
+        state.store_reference(extra.build()?)?;
+        Ok(state.build()?)
+    }
+
+    /// Backward link from a new block with the specified previous blocks
+    /// to one of them.
+    pub(crate) fn backward_link(
+        (from_seqno, prev_blocks): (u32, &[(BlockId, bool)]),
+        (to_block, to_seqno): (&Cell, u32),
+        to_key_block: bool,
+    ) -> Result<BlockLinkBack> {
+        let state = mc_state(prev_blocks)?;
+        let from_block = build_block_with_state(false, None, Some(&state))?;
+
+        Ok(BlockLinkBack {
+            to_key_block,
+            from: block_id(&from_block, from_seqno),
+            to: block_id(to_block, to_seqno),
+            dest_proof: full_proof(to_block)?,
+            proof: full_proof(&from_block)?,
+            state_proof: full_proof(&state)?,
+        })
+    }
+
+    #[test]
+    fn backward_link_is_checked() -> Result<()> {
+        let config = key_block_config(&validator_keys())?;
+        let key_block = build_block(true, Some(&config))?;
+        let other_block = build_block(false, None)?;
+        let registered = [
+            (block_id(&key_block, 10), true),
+            (block_id(&other_block, 11), false),
+        ];
+
+        check_backward_link(&backward_link((12, &registered), (&key_block, 10), true)?)?;
+        check_backward_link(&backward_link(
+            (12, &registered),
+            (&other_block, 11),
+            false,
+        )?)?;
+
+        // Key block flag must match the block and the registered one
+        let link = backward_link((12, &registered), (&key_block, 10), false)?;
+        assert!(check_backward_link(&link).is_err());
+
+        // Block with another hash
+        let link = backward_link((12, &registered), (&other_block, 10), false)?;
+        assert!(check_backward_link(&link).is_err());
+
+        // Block is not registered
+        let link = backward_link((12, &registered[1..]), (&key_block, 10), true)?;
+        assert!(check_backward_link(&link).is_err());
+
+        // Proofs of another source block
+        let mut link = backward_link((12, &registered), (&key_block, 10), true)?;
+        link.from.root_hash = HashBytes::ZERO;
+        assert!(check_backward_link(&link).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn block_signing_data_layout() {
+        let block_id = BlockId {
+            shard: ShardIdent::MASTERCHAIN,
+            seqno: 1,
+            root_hash: HashBytes([1; 32]),
+            file_hash: HashBytes([2; 32]),
+        };
+
+        // Serialized TL id of `ton.blockId` as it goes on the wire
+        let mut expected = vec![0x70, 0x6e, 0x0b, 0xc5];
+        expected.extend_from_slice(&[1; 32]);
+        expected.extend_from_slice(&[2; 32]);
+        assert_eq!(block_signing_data(&block_id), expected);
+
+        // Serialized TL id of `pub.ed25519`
+        let mut hasher = Sha256::new();
+        hasher.update([0xc6, 0xb4, 0x13, 0x48]);
+        hasher.update([3; 32]);
+        assert_eq!(
+            node_id_short(&HashBytes([3; 32])),
+            HashBytes(hasher.finalize().into())
+        );
+    }
+
+    #[test]
+    fn forward_link_is_checked() -> Result<()> {
+        let keys = validator_keys();
+
+        let config = key_block_config(&keys)?;
+        let key_block = build_block(true, Some(&config))?;
+        let loaded = load_key_block_config(key_block.as_ref())?;
+        assert_eq!(loaded.address, config.address);
+        assert!(loaded.get::<ConfigParam34>()?.is_some());
+        assert!(load_key_block_config(build_block(false, None)?.as_ref()).is_err());
+
+        check_forward_link(&forward_link(&keys)?)?;
+        check_forward_link(&forward_link(&keys[..3])?)?;
+
+        // Half of the weight is not enough
+        let link = forward_link(&keys[..2])?;
+        let error = check_forward_link(&link).unwrap_err();
+        assert!(error.to_string().contains("not enough signatures"));
+
+        let mut link = forward_link(&keys)?;
+        link.signatures[0].signature[0] ^= 1;
+        assert!(check_forward_link(&link).is_err());
+
+        let mut link = forward_link(&keys)?;
+        link.signatures[1].node_id_short = link.signatures[0].node_id_short;
+        assert!(check_forward_link(&link).is_err());
+
+        let mut link = forward_link(&keys)?;
+        link.signatures[0] = BlockSignature {
+            node_id_short: node_id_short(&HashBytes([0xff; 32])),
+            signature: link.signatures[0].signature.clone(),
+        };
+        assert!(check_forward_link(&link).is_err());
+
+        let mut link = forward_link(&keys)?;
+        link.to_key_block = true;
+        assert!(check_forward_link(&link).is_err());
+
+        let mut link = forward_link(&keys)?;
+        link.validator_set_hash ^= 1;
+        assert!(check_forward_link(&link).is_err());
+        Ok(())
+    }
+
+    fn shard_descr(seqno: u32, root_hash: HashBytes) -> Result<Cell> {
+        let mut leaf = CellBuilder::new();
+        leaf.store_bit_zero()?;
+        leaf.store_small_uint(0xa, 4)?;
+        leaf.store_u32(seqno)?;
+        leaf.store_zeros(32 + 64 + 64)?;
+        leaf.store_u256(&root_hash)?;
+        leaf.store_u256(&HashBytes([seqno as u8; 32]))?;
+        Ok(leaf.build()?)
+    }
+
+    #[test]
+    fn shard_block_is_found_in_pruned_tree() -> Result<()> {
+        let (left, right) = ShardIdent::BASECHAIN.split().unwrap();
+        let left_leaf = shard_descr(5, HashBytes([1; 32]))?;
+        let right_leaf = shard_descr(6, HashBytes([2; 32]))?;
+
+        let mut fork = CellBuilder::new();
+        fork.store_bit_one()?;
+        fork.store_reference(left_leaf.clone())?;
+        fork.store_reference(right_leaf)?;
+        let fork = fork.build()?;
+
+        // Lite servers prune shards which are not requested
+        let proof = MerkleProof::create(
+            fork.as_ref(),
+            HashSet::from([*fork.repr_hash(), *left_leaf.repr_hash()]),
+        )
+        .build()?;
+
+        let block = |shard, seqno: u32, root_hash| BlockId {
+            shard,
+            seqno,
+            root_hash,
+            file_hash: HashBytes([seqno as u8; 32]),
+        };
+        let tree = proof.cell.as_ref();
+        assert!(contains_shard_block(
+            tree,
+            &block(left, 5, HashBytes([1; 32]))
+        )?);
+        assert!(!contains_shard_block(
+            tree,
+            &block(left, 4, HashBytes([1; 32]))
+        )?);
+        assert!(!contains_shard_block(
+            tree,
+            &block(right, 6, HashBytes([2; 32]))
+        )?);
+        Ok(())
+    }
+
+    #[test]
+    fn block_proof_must_match_block_id() -> Result<()> {
+        let block = CellBuilder::build_from(0xdeadbeefu32)?;
+        let proof =
+            MerkleProof::create(block.as_ref(), HashSet::from([*block.repr_hash()])).build()?;
+        let proof = CellBuilder::build_from(proof)?;
+
+        let mut block_id = BlockId {
+            shard: ShardIdent::MASTERCHAIN,
+            seqno: 1,
+            root_hash: *block.repr_hash(),
+            file_hash: HashBytes::ZERO,
+        };
+        let root = check_block_proof(proof.as_ref(), &block_id)?;
+        assert_eq!(root.hash(0), block.repr_hash());
+
+        block_id.root_hash = HashBytes::ZERO;
+        assert!(check_block_proof(proof.as_ref(), &block_id).is_err());
+        Ok(())
+    }
+}
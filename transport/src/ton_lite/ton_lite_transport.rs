@@ -1,102 +1,503 @@
 use anyhow::{Context, Result};
-use tycho_types::boc::BocRepr;
-use tycho_types::cell::HashBytes;
-use tycho_types::merkle::MerkleProof;
-use tycho_types::models::{BlockId, OptionalAccount, OwnedMessage, StdAddr, Transaction};
-use tycho_types::prelude::{Boc, Cell, CellFamily, CellSlice, Load};
+use futures_util::Stream;
 use nekoton_core::models::GenTimings;
-use nekoton_core::models::{ContractState, LatestBlockchainConfig};
+use nekoton_core::models::{ContractState, LastTransactionId, LatestBlockchainConfig};
 use nekoton_core::transport::Transport;
+use nekoton_utils::time::now_sec_u64;
 use proof_api_util::block::{BlockchainBlock, BlockchainModels, TonModels};
 use std::sync::Arc;
-use std::time::Duration;
-use tokio::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
 use tokio::task::AbortHandle;
 use ton_lite_client::{LiteClient, LiteClientConfig, NodeInfo};
+use tycho_types::boc::BocRepr;
+use tycho_types::cell::HashBytes;
+use tycho_types::models::{BlockId, MsgInfo, OptionalAccount, OwnedMessage, StdAddr, Transaction};
+use tycho_types::prelude::{Boc, Cell, CellBuilder, CellFamily};
 
 use crate::options::BlockchainOptions;
-use crate::ton_lite::models::{ParsedProofs, TonMcStateExtraShort};
+use crate::rpc::TransportError;
+use crate::ton_lite::lite_api::{BlockLink, LiteApi, RawAccountState};
+use crate::ton_lite::models::ParsedProofs;
+use crate::ton_lite::proofs;
+use crate::ton_lite::tx_cache::{MessageDestination, TransactionCache, TransactionLocation};
+
+const TRANSACTIONS_PAGE_SIZE: u8 = 16;
+/// Number of ping intervals after which the polled masterchain block
+/// is considered stale.
+const MC_BLOCK_MAX_AGE_INTERVALS: u32 = 3;
+const MAX_SCANNED_TRANSACTIONS: usize = 256;
+/// Time to wait for the transaction of an expired external message,
+/// it could have been included into a block before the expiration.
+const EXPIRED_MESSAGE_MARGIN: Duration = Duration::from_secs(30);
 
 pub struct TonLiteTransport {
     inner: Arc<Inner>,
 }
 
 struct Inner {
-    client: LiteClient,
+    client: Arc<dyn LiteApi>,
+    options: TonLiteTransportOptions,
 
-    last_mc_block: Arc<Mutex<Option<BlockId>>>,
-    ping_interval: Duration,
-    mc_block_task: Option<AbortHandle>,
+    /// Latest masterchain block and when it was last confirmed.
+    last_mc_block: watch::Receiver<Option<(BlockId, Instant)>>,
+    mc_block_task: parking_lot::Mutex<McBlockTask>,
+    transactions: parking_lot::Mutex<TransactionCache>,
+    trusted: parking_lot::Mutex<Option<TrustedChain>>,
+}
 
-    bc_options: BlockchainOptions,
+enum McBlockTask {
+    /// Not spawned yet, started on the first use inside a runtime.
+    Pending(watch::Sender<Option<(BlockId, Instant)>>),
+    Running(AbortHandle),
+    Stopped,
 }
 
-impl Clone for Inner {
-    fn clone(&self) -> Self {
-        Self {
-            client: self.client.clone(),
-            last_mc_block: self.last_mc_block.clone(),
-            ping_interval: self.ping_interval,
-            mc_block_task: None,
-            bc_options: self.bc_options.clone(),
-        }
-    }
+/// Masterchain blocks verified from a trusted checkpoint.
+#[derive(Clone, Copy)]
+struct TrustedChain {
+    key_block: BlockId,
+    last_verified: BlockId,
 }
 
 impl Inner {
-    async fn update_last_mc_block_task(self) {
-        let mut interval = tokio::time::interval(self.ping_interval);
-        loop {
-            interval.tick().await;
-            if let Ok(block) = self.client.get_last_mc_block_id().await {
-                let mut guard = self.last_mc_block.lock().await;
-                *guard = Some(block);
+    /// Spawns the masterchain block polling task if it was not started yet.
+    ///
+    /// Returns `false` if the transport was shut down.
+    fn ensure_started(&self) -> bool {
+        let mut task = self.mc_block_task.lock();
+        match std::mem::replace(&mut *task, McBlockTask::Stopped) {
+            McBlockTask::Pending(sender) => {
+                let handle = tokio::spawn(update_last_mc_block_task(
+                    self.client.clone(),
+                    sender,
+                    self.options.ping_interval,
+                ));
+                *task = McBlockTask::Running(handle.abort_handle());
+                true
             }
+            running @ McBlockTask::Running(_) => {
+                *task = running;
+                true
+            }
+            McBlockTask::Stopped => false,
+        }
+    }
+
+    fn shutdown(&self) {
+        let task = std::mem::replace(&mut *self.mc_block_task.lock(), McBlockTask::Stopped);
+        if let McBlockTask::Running(handle) = task {
+            handle.abort();
+        }
+    }
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+async fn update_last_mc_block_task(
+    client: Arc<dyn LiteApi>,
+    sender: watch::Sender<Option<(BlockId, Instant)>>,
+    ping_interval: Duration,
+) {
+    let mut interval = tokio::time::interval(ping_interval);
+    loop {
+        interval.tick().await;
+        if let Ok(block) = client.get_last_mc_block_id().await {
+            // Subscribers are notified only about new blocks
+            sender.send_if_modified(|current| {
+                let modified = current.map(|(current, _)| current) != Some(block);
+                *current = Some((block, Instant::now()));
+                modified
+            });
         }
     }
 }
 
 impl TonLiteTransport {
-    pub fn new<I>(config: LiteClientConfig, nodes: I) -> Self
+    pub fn new<I>(config: LiteClientConfig, nodes: I, options: TonLiteTransportOptions) -> Self
     where
         I: IntoIterator<Item = NodeInfo>,
     {
-        let mut inner = Inner {
-            client: LiteClient::new(config, nodes),
-            last_mc_block: Arc::default(),
-            ping_interval: Duration::from_secs(5),
-
-            mc_block_task: None,
-            bc_options: Default::default(),
-        };
+        Self::with_client(Arc::new(LiteClient::new(config, nodes)), options)
+    }
 
-        inner.mc_block_task =
-            Some(tokio::spawn(inner.clone().update_last_mc_block_task()).abort_handle());
+    /// Creates a transport over an arbitrary lite-server client.
+    ///
+    /// Doesn't require a running tokio runtime, the background polling
+    /// task is spawned on the first request.
+    pub fn with_client(client: Arc<dyn LiteApi>, options: TonLiteTransportOptions) -> Self {
+        let (sender, last_mc_block) = watch::channel(None);
+        let trusted = options.trusted_key_block.map(|key_block| TrustedChain {
+            key_block,
+            last_verified: key_block,
+        });
 
         Self {
-            inner: Arc::new(inner),
+            inner: Arc::new(Inner {
+                client,
+                last_mc_block,
+                mc_block_task: parking_lot::Mutex::new(McBlockTask::Pending(sender)),
+                transactions: parking_lot::Mutex::new(TransactionCache::new(
+                    options.transaction_cache_capacity,
+                )),
+                trusted: parking_lot::Mutex::new(trusted),
+                options,
+            }),
         }
     }
 
+    /// Stops the background polling task.
+    ///
+    /// The transport remains usable but queries the latest masterchain
+    /// block on every request, and block streams are finished.
+    pub fn shutdown(&self) {
+        self.inner.shutdown();
+    }
+
+    /// Returns a stream of new masterchain blocks.
+    ///
+    /// The stream ends when the transport is shut down or dropped.
+    pub fn mc_blocks(&self) -> impl Stream<Item = BlockId> + Send + 'static {
+        let inner = Arc::downgrade(&self.inner);
+        let mut last_mc_block = self.inner.last_mc_block.clone();
+        last_mc_block.mark_changed();
+
+        futures_util::stream::unfold(
+            (inner, last_mc_block),
+            |(inner, mut last_mc_block)| async move {
+                if !inner.upgrade()?.ensure_started() {
+                    return None;
+                }
+
+                loop {
+                    last_mc_block.changed().await.ok()?;
+                    if let Some((block, _)) = *last_mc_block.borrow_and_update() {
+                        return Some((block, (inner, last_mc_block)));
+                    }
+                }
+            },
+        )
+    }
+
+    /// Returns the block polled by the background task, or queries
+    /// the lite server if the task is stopped or hasn't updated it
+    /// for several ping intervals, e.g. when its runtime has shut down.
     async fn get_last_mc_block_id(&self) -> Result<BlockId> {
-        let guard = self.inner.last_mc_block.lock().await;
-        match guard.as_ref() {
-            Some(block) => Ok(*block),
-            None => self.inner.client.get_last_mc_block_id().await,
+        if self.inner.ensure_started() {
+            let max_age = self.inner.options.ping_interval * MC_BLOCK_MAX_AGE_INTERVALS;
+            if let Some((block, updated_at)) = *self.inner.last_mc_block.borrow() {
+                if updated_at.elapsed() < max_age {
+                    return Ok(block);
+                }
+            }
+        }
+        self.inner.client.get_last_mc_block_id().await
+    }
+
+    /// Sets a trusted masterchain key block.
+    ///
+    /// All masterchain blocks used afterwards must be linked to it
+    /// by a chain of blocks signed by validators. Without a checkpoint
+    /// the latest block reported by the lite server is trusted as is.
+    pub async fn set_trusted_key_block(&self, key_block: BlockId) {
+        *self.inner.trusted.lock() = Some(TrustedChain {
+            key_block,
+            last_verified: key_block,
+        });
+    }
+
+    /// Returns the latest masterchain block linked to the trusted checkpoint.
+    async fn get_trusted_mc_block_id(&self) -> Result<BlockId> {
+        let block = self.get_last_mc_block_id().await?;
+
+        // The lock is not held while the proofs are fetched
+        let chain = *self.inner.trusted.lock();
+        let Some(chain) = chain else {
+            return Ok(block);
+        };
+        if chain.last_verified == block {
+            return Ok(block);
+        }
+
+        let mut key_block = chain.key_block;
+        let mut current = chain.key_block;
+        loop {
+            let proof = self
+                .inner
+                .client
+                .get_block_proof(&current, Some(&block))
+                .await?;
+            anyhow::ensure!(
+                !proof.steps.is_empty() || proof.complete,
+                "block proof chain is empty"
+            );
+
+            for step in proof.steps {
+                // Each link starts from an already verified block
+                let (to, to_key_block) = match step {
+                    BlockLink::Forward(link) => {
+                        anyhow::ensure!(link.from == current, "block proof chain is broken");
+                        proofs::check_forward_link(&link)?;
+                        (link.to, link.to_key_block)
+                    }
+                    BlockLink::Back(link) => {
+                        anyhow::ensure!(link.from == current, "block proof chain is broken");
+                        proofs::check_backward_link(&link)?;
+                        (link.to, link.to_key_block)
+                    }
+                };
+
+                current = to;
+                if to_key_block && to.seqno > key_block.seqno {
+                    key_block = to;
+                }
+            }
+
+            if proof.complete {
+                break;
+            }
+        }
+
+        anyhow::ensure!(
+            current == block,
+            "block proof chain does not reach block {block}"
+        );
+
+        let mut trusted = self.inner.trusted.lock();
+        if let Some(trusted) = trusted.as_mut() {
+            // Skip the update if the checkpoint was replaced meanwhile
+            // or a newer block was already verified
+            if trusted.key_block == chain.key_block && trusted.last_verified.seqno < block.seqno {
+                trusted.key_block = key_block;
+                trusted.last_verified = block;
+            }
+        }
+        Ok(block)
+    }
+
+    /// Returns an account state with checked proofs.
+    async fn get_verified_account_state(
+        &self,
+        address: &StdAddr,
+    ) -> Result<(RawAccountState, ParsedProofs)> {
+        let latest_block = self.get_trusted_mc_block_id().await?;
+        let account_state = self
+            .inner
+            .client
+            .get_account_state(&latest_block, address)
+            .await?;
+
+        if check_account_shard(&latest_block, &account_state.shard_block, address)? {
+            proofs::check_shard_proof(
+                &latest_block,
+                &account_state.shard_block,
+                &account_state.shard_proof,
+            )?;
+        }
+
+        let proofs = parse_proofs(&account_state.proof, &account_state.shard_block)?;
+        Ok((account_state, proofs))
+    }
+
+    /// Returns up to `count` account transactions starting from `from`
+    /// and going back in time. Newest transactions go first.
+    ///
+    /// Transactions are checked to be linked to `from` by their hashes,
+    /// so a proven `from` makes the whole list trusted.
+    pub async fn get_account_transactions(
+        &self,
+        address: &StdAddr,
+        from: &LastTransactionId,
+        count: u8,
+    ) -> Result<Vec<Transaction>> {
+        let data = self
+            .inner
+            .client
+            .get_transactions(address, from.lt, &from.hash, count)
+            .await?;
+
+        let transactions = parse_transactions(&data)?;
+        check_transactions_chain(from, &transactions)?;
+
+        let mut cache = self.inner.transactions.lock();
+        Ok(transactions
+            .into_iter()
+            .map(|(hash, tx)| {
+                cache.insert(hash, address, &tx);
+                tx
+            })
+            .collect())
+    }
+
+    /// Fetches a single account transaction.
+    pub async fn get_account_transaction(
+        &self,
+        address: &StdAddr,
+        id: &LastTransactionId,
+    ) -> Result<Option<Transaction>> {
+        let mut transactions = self.get_account_transactions(address, id, 1).await?;
+        Ok(transactions.pop())
+    }
+
+    /// Remembers the account of a transaction, so that it can be found
+    /// by hash with [`Transport::get_transaction`], e.g. as a trace root.
+    pub fn remember_transaction(&self, address: StdAddr, id: &LastTransactionId) {
+        self.inner
+            .transactions
+            .lock()
+            .remember_location(id.hash, TransactionLocation { address, lt: id.lt });
+    }
+
+    /// Sends the message and waits for its transaction until the message
+    /// expires, but not longer than
+    /// [`max_message_wait`](TonLiteTransportOptions::max_message_wait).
+    ///
+    /// `expire_at` is the expiration time set by the message author,
+    /// e.g. the `expire` header of an ABI call. Messages without one
+    /// can be sent with [`Transport::send_message_reliable`].
+    pub async fn send_message_reliable_until(
+        &self,
+        message: &OwnedMessage,
+        expire_at: u32,
+    ) -> Result<Transaction> {
+        let wait = message_wait(
+            expire_at,
+            now_sec_u64(),
+            self.inner.options.max_message_wait,
+        );
+        self.send_and_wait(message, Some(tokio::time::Instant::now() + wait))
+            .await
+    }
+
+    /// Polls the transaction of the sent message until the deadline,
+    /// or the configured number of attempts if it is not set.
+    async fn send_and_wait(
+        &self,
+        message: &OwnedMessage,
+        deadline: Option<tokio::time::Instant>,
+    ) -> Result<Transaction> {
+        let address = match &message.info {
+            MsgInfo::Int(info) => info.dst.as_std(),
+            MsgInfo::ExtIn(info) => info.dst.as_std(),
+            MsgInfo::ExtOut(_) => anyhow::bail!("unsupported message type"),
+        }
+        .context("unsupported destination address")?
+        .clone();
+
+        let min_lt = self
+            .get_last_transaction_id(&address)
+            .await?
+            .map(|id| id.lt)
+            .unwrap_or_default();
+
+        let hash = *CellBuilder::build_from(message)?.repr_hash();
+        self.inner
+            .transactions
+            .lock()
+            .remember_destination(hash, MessageDestination { address, min_lt });
+
+        self.send_message(message).await?;
+
+        let options = &self.inner.options.blockchain;
+        let mut attempts = 0;
+        loop {
+            tokio::time::sleep(options.message_poll_interval).await;
+
+            if let Some(transaction) = self.get_dst_transaction(&hash).await? {
+                return Ok(transaction);
+            }
+
+            let timed_out = match deadline {
+                Some(deadline) => tokio::time::Instant::now() >= deadline,
+                None => {
+                    attempts += 1;
+                    attempts >= options.message_poll_attempts
+                }
+            };
+            if timed_out {
+                return Err(TransportError::MessageTimeout.into());
+            }
         }
     }
+
+    async fn get_last_transaction_id(
+        &self,
+        address: &StdAddr,
+    ) -> Result<Option<LastTransactionId>> {
+        let (account_state, proofs) = self.get_verified_account_state(address).await?;
+        if account_state.state.is_empty() {
+            return Ok(None);
+        }
+
+        proofs
+            .get_last_transaction_id(&address.address)
+            .context("failed to get last transaction id")
+            .map(Some)
+    }
+
+    /// Scans the destination account for a transaction with the specified
+    /// inbound message.
+    async fn find_dst_transaction(
+        &self,
+        message_hash: &HashBytes,
+        dst: &MessageDestination,
+    ) -> Result<Option<Transaction>> {
+        let Some(from) = self.get_last_transaction_id(&dst.address).await? else {
+            return Ok(None);
+        };
+        self.scan_dst_transaction(message_hash, dst, from).await
+    }
+
+    /// Scans account transactions back in time starting from `from`.
+    async fn scan_dst_transaction(
+        &self,
+        message_hash: &HashBytes,
+        dst: &MessageDestination,
+        mut from: LastTransactionId,
+    ) -> Result<Option<Transaction>> {
+        let mut scanned = 0;
+        while scanned < MAX_SCANNED_TRANSACTIONS && from.lt > dst.min_lt {
+            let transactions = self
+                .get_account_transactions(&dst.address, &from, TRANSACTIONS_PAGE_SIZE)
+                .await?;
+
+            let Some(last) = transactions.last() else {
+                break;
+            };
+            from = LastTransactionId {
+                lt: last.prev_trans_lt,
+                hash: last.prev_trans_hash,
+            };
+            scanned += transactions.len();
+
+            for tx in transactions {
+                if tx.lt <= dst.min_lt {
+                    return Ok(None);
+                }
+
+                if matches!(&tx.in_msg, Some(in_msg) if in_msg.repr_hash() == message_hash) {
+                    return Ok(Some(tx));
+                }
+            }
+        }
+
+        Ok(None)
+    }
 }
 
 #[async_trait::async_trait]
 impl Transport for TonLiteTransport {
     async fn send_message(&self, message: &OwnedMessage) -> Result<()> {
         let message_bytes = BocRepr::encode(message)?;
-        let _result = self.inner.client.send_message(message_bytes).await?;
-        Ok(())
+        self.inner.client.send_message(message_bytes).await
     }
 
-    async fn send_message_reliable(&self, _: &OwnedMessage) -> Result<Transaction> {
-        todo!()
+    async fn send_message_reliable(&self, message: &OwnedMessage) -> Result<Transaction> {
+        self.send_and_wait(message, None).await
     }
 
     async fn get_contract_state(
@@ -104,15 +505,9 @@ impl Transport for TonLiteTransport {
         address: &StdAddr,
         last_transaction_lt: Option<u64>,
     ) -> Result<ContractState> {
-        let latest_block = self.get_last_mc_block_id().await?;
-        let account_state = self
-            .inner
-            .client
-            .get_account(&latest_block, address)
-            .await?;
-
-        let proofs = parse_proofs(account_state.proof)?;
+        let (account_state, proofs) = self.get_verified_account_state(address).await?;
         if account_state.state.is_empty() {
+            proofs.check_account_state(&address.address, None)?;
             return Ok(ContractState::NotExists {
                 timings: proofs.timings,
             });
@@ -131,6 +526,8 @@ impl Transport for TonLiteTransport {
         }
 
         let cell = Boc::decode(&account_state.state)?;
+        proofs.check_account_state(&address.address, Some(cell.as_ref()))?;
+
         let OptionalAccount(Some(account)) = cell.parse()? else {
             return Ok(ContractState::NotExists {
                 timings: proofs.timings,
@@ -145,19 +542,17 @@ impl Transport for TonLiteTransport {
     }
 
     async fn get_config(&self) -> Result<LatestBlockchainConfig> {
-        let latest_block = self.get_last_mc_block_id().await?;
+        let latest_block = self.get_trusted_mc_block_id().await?;
 
         let config = self.inner.client.get_config(&latest_block).await?;
-        let state_proof = Boc::decode(&config.config_proof)?
-            .parse_exotic::<MerkleProof>()?
-            .cell;
+        let block_root =
+            proofs::check_block_proof(Boc::decode(&config.state_proof)?.as_ref(), &latest_block)?;
+        let state_root = proofs::check_state_proof(
+            block_root.as_ref(),
+            Boc::decode(&config.config_proof)?.as_ref(),
+        )?;
 
-        let mut cs: CellSlice<'_> = state_proof.as_slice()?;
-        cs.only_last(1, 1)?;
-        let extra = <Option<Cell>>::load_from(&mut cs)
-            .context("failed to read McStateExtra")?
-            .context("expected McStateExtra")?
-            .parse::<TonMcStateExtraShort>()?;
+        let extra = proofs::load_mc_state_extra(state_root.as_ref())?;
 
         let global_id = extra.config.get_global_id()?;
         let config = LatestBlockchainConfig {
@@ -169,36 +564,169 @@ impl Transport for TonLiteTransport {
         Ok(config)
     }
 
-    async fn get_transaction(&self, _: &HashBytes) -> Result<Option<Transaction>> {
-        todo!()
+    /// Lite servers look up transactions by account, so the account must be
+    /// known from a transaction seen by this transport (e.g. while scanning
+    /// accounts) or set with [`TonLiteTransport::remember_transaction`].
+    async fn get_transaction(&self, hash: &HashBytes) -> Result<Option<Transaction>> {
+        let location = {
+            let cache = self.inner.transactions.lock();
+            if let Some(tx) = cache.get(hash) {
+                return Ok(Some(tx));
+            }
+            cache.get_location(hash)
+        };
+
+        match location {
+            Some(location) => {
+                let id = LastTransactionId {
+                    lt: location.lt,
+                    hash: *hash,
+                };
+                self.get_account_transaction(&location.address, &id).await
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Destination must be known from a previously seen transaction
+    /// or a message sent through this transport.
+    async fn get_dst_transaction(&self, message_hash: &HashBytes) -> Result<Option<Transaction>> {
+        let dst = {
+            let cache = self.inner.transactions.lock();
+            if let Some(tx) = cache.get_by_in_msg(message_hash) {
+                return Ok(Some(tx));
+            }
+            cache.get_destination(message_hash)
+        };
+
+        match dst {
+            Some(dst) => self.find_dst_transaction(message_hash, &dst).await,
+            None => Ok(None),
+        }
     }
+}
+
+#[derive(Debug, Clone)]
+pub struct TonLiteTransportOptions {
+    /// How often the latest masterchain block is polled.
+    ///
+    /// Default: `5 sec`
+    pub ping_interval: Duration,
+
+    /// Trusted masterchain key block to verify all used blocks against.
+    ///
+    /// Default: `None`
+    pub trusted_key_block: Option<BlockId>,
+
+    /// Amount of seen transactions to keep for lookups by hash.
+    ///
+    /// Default: `10000`
+    pub transaction_cache_capacity: usize,
+
+    /// Longest time to wait for the transaction of a message sent with
+    /// [`TonLiteTransport::send_message_reliable_until`].
+    ///
+    /// Default: `5 min`
+    pub max_message_wait: Duration,
+
+    /// Message polling options.
+    pub blockchain: BlockchainOptions,
+}
 
-    async fn get_dst_transaction(&self, _: &HashBytes) -> Result<Option<Transaction>> {
-        todo!()
+impl Default for TonLiteTransportOptions {
+    fn default() -> Self {
+        Self {
+            ping_interval: Duration::from_secs(5),
+            trusted_key_block: None,
+            transaction_cache_capacity: 10_000,
+            max_message_wait: Duration::from_secs(300),
+            blockchain: Default::default(),
+        }
     }
 }
 
-fn parse_proofs(proofs: Vec<u8>) -> Result<ParsedProofs> {
+fn parse_transactions(data: &[u8]) -> Result<Vec<(HashBytes, Transaction)>> {
     use tycho_types::boc::de::{BocHeader, Options};
 
+    if data.is_empty() {
+        return Ok(Vec::new());
+    }
+
     let header = BocHeader::decode(
-        &proofs,
+        data,
         &Options {
-            max_roots: Some(2),
-            min_roots: Some(2),
+            max_roots: None,
+            min_roots: None,
         },
     )?;
 
-    let block_proof_id = *header.roots().first().context("block proof not found")?;
-    let state_proof_id = *header.roots().get(1).context("state proof not found")?;
+    let roots = header.roots().to_vec();
     let cells = header.finalize(Cell::empty_context())?;
 
-    let block = cells
-        .get(block_proof_id)
-        .context("block proof not found")?
-        .parse_exotic::<MerkleProof>()?
-        .cell
-        .parse::<<TonModels as BlockchainModels>::Block>()?;
+    roots
+        .into_iter()
+        .map(|id| {
+            let cell = cells.get(id).context("transaction not found")?;
+            Ok((*cell.repr_hash(), cell.parse::<Transaction>()?))
+        })
+        .collect()
+}
+
+/// Checks that each transaction is the previous one of the transaction before it,
+/// starting from `from`.
+fn check_transactions_chain(
+    from: &LastTransactionId,
+    transactions: &[(HashBytes, Transaction)],
+) -> Result<()> {
+    let mut expected = (from.hash, from.lt);
+    for (hash, tx) in transactions {
+        anyhow::ensure!(
+            (*hash, tx.lt) == expected,
+            "transaction {hash} is not linked to the requested one"
+        );
+        expected = (tx.prev_trans_hash, tx.prev_trans_lt);
+    }
+    Ok(())
+}
+
+/// Checks that the shard block returned with the account state can contain it.
+///
+/// Returns whether the shard block must be proven by the masterchain block,
+/// masterchain accounts are only served from the masterchain block itself.
+fn check_account_shard(
+    mc_block: &BlockId,
+    shard_block: &BlockId,
+    address: &StdAddr,
+) -> Result<bool> {
+    let shard = &shard_block.shard;
+    anyhow::ensure!(
+        shard.workchain() == address.workchain as i32 && shard.contains_account(&address.address),
+        "shard {shard} does not contain account {address}"
+    );
+
+    if address.is_masterchain() {
+        anyhow::ensure!(
+            shard_block == mc_block,
+            "masterchain account state must be taken from block {mc_block}"
+        );
+        Ok(false)
+    } else {
+        Ok(true)
+    }
+}
+
+/// Time to wait for the transaction of a message which expires at `expire_at`,
+/// capped by `max_wait`.
+fn message_wait(expire_at: u32, now: u64, max_wait: Duration) -> Duration {
+    let expire_at = expire_at as u64 + EXPIRED_MESSAGE_MARGIN.as_secs();
+    Duration::from_secs(expire_at.saturating_sub(now)).min(max_wait)
+}
+
+fn parse_proofs(proofs: &[u8], block_id: &BlockId) -> Result<ParsedProofs> {
+    let roots = proofs::decode_roots(proofs, 2)?;
+
+    let block_root = proofs::check_block_proof(roots[0].as_ref(), block_id)?;
+    let block = block_root.parse::<<TonModels as BlockchainModels>::Block>()?;
 
     let info = block.load_info()?;
     let timings = GenTimings {
@@ -206,14 +734,524 @@ fn parse_proofs(proofs: Vec<u8>) -> Result<ParsedProofs> {
         gen_utime: info.gen_utime,
     };
 
-    let state_root = cells
-        .get(state_proof_id)
-        .context("state proof not found")?
-        .parse_exotic::<MerkleProof>()?
-        .cell;
+    let state_root = proofs::check_state_proof(block_root.as_ref(), roots[1].as_ref())?;
 
     Ok(ParsedProofs {
         timings,
         state_root,
     })
 }
+
+#[cfg(test)]
+mod test {
+    use nekoton_core::contracts::blockchain_context::MessageBuilder;
+    use tycho_types::cell::Lazy;
+    use tycho_types::models::{
+        AccountStatus, ComputePhase, ComputePhaseSkipReason, HashUpdate, IntAddr, OrdinaryTxInfo,
+        ShardIdent, SkippedComputePhase, TxInfo,
+    };
+
+    use super::*;
+    use crate::ton_lite::lite_api::{PartialBlockProof, RawConfigProof};
+    use crate::ton_lite::proofs::tests::{
+        backward_link, block_id, build_block, key_block_config, signed_link, validator_keys,
+    };
+
+    /// Lite server stand-in which serves canned responses.
+    #[derive(Default)]
+    struct CannedLiteServer {
+        last_mc_block: parking_lot::Mutex<Option<BlockId>>,
+        /// Pages of block links, served in order.
+        block_proofs: parking_lot::Mutex<std::collections::VecDeque<Vec<BlockLink>>>,
+        account_state: Option<(Vec<u8>, Vec<u8>)>,
+        config_proof: Vec<u8>,
+        /// Account transactions, newest first.
+        transactions: Vec<Cell>,
+        sent: parking_lot::Mutex<Vec<Vec<u8>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl LiteApi for CannedLiteServer {
+        async fn get_last_mc_block_id(&self) -> Result<BlockId> {
+            Ok(self.last_mc_block.lock().unwrap_or(BlockId {
+                shard: ShardIdent::MASTERCHAIN,
+                seqno: 1,
+                root_hash: HashBytes::ZERO,
+                file_hash: HashBytes::ZERO,
+            }))
+        }
+
+        async fn get_account_state(&self, block: &BlockId, _: &StdAddr) -> Result<RawAccountState> {
+            let (state, proof) = self
+                .account_state
+                .clone()
+                .context("account state not found")?;
+            Ok(RawAccountState {
+                shard_block: *block,
+                shard_proof: Vec::new(),
+                state,
+                proof,
+            })
+        }
+
+        async fn get_config(&self, _: &BlockId) -> Result<RawConfigProof> {
+            Ok(RawConfigProof {
+                state_proof: self.config_proof.clone(),
+                config_proof: self.config_proof.clone(),
+            })
+        }
+
+        async fn get_block_proof(
+            &self,
+            known_block: &BlockId,
+            _: Option<&BlockId>,
+        ) -> Result<PartialBlockProof> {
+            let mut pages = self.block_proofs.lock();
+            let steps = pages.pop_front().unwrap_or_default();
+            Ok(PartialBlockProof {
+                complete: pages.is_empty(),
+                from: *known_block,
+                to: *known_block,
+                steps,
+            })
+        }
+
+        async fn get_transactions(
+            &self,
+            _: &StdAddr,
+            _: u64,
+            hash: &HashBytes,
+            count: u8,
+        ) -> Result<Vec<u8>> {
+            use tycho_types::boc::ser::BocHeader;
+
+            let mut transactions = self
+                .transactions
+                .iter()
+                .skip_while(|tx| tx.repr_hash() != hash)
+                .take(count as usize);
+            let Some(first) = transactions.next() else {
+                return Ok(Vec::new());
+            };
+
+            let mut header =
+                BocHeader::<std::collections::hash_map::RandomState>::with_root(first.as_ref());
+            for tx in transactions {
+                header.add_root(tx.as_ref());
+            }
+            let mut data = Vec::new();
+            header.encode(&mut data);
+            Ok(data)
+        }
+
+        async fn send_message(&self, message: Vec<u8>) -> Result<()> {
+            self.sent.lock().push(message);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn send_message_offline() -> Result<()> {
+        let server = Arc::new(CannedLiteServer::default());
+        let transport = TonLiteTransport::with_client(server.clone(), Default::default());
+
+        let dst = IntAddr::Std(StdAddr::new(0, HashBytes::ZERO));
+        let message = MessageBuilder::new_external_in(dst).build();
+        transport.send_message(&message).await?;
+
+        let sent = server.sent.lock();
+        assert_eq!(sent.as_slice(), [BocRepr::encode(&message)?]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_proofs() {
+        let server = Arc::new(CannedLiteServer {
+            account_state: Some((Vec::new(), vec![0xb5, 0xee])),
+            config_proof: vec![0xb5, 0xee],
+            ..Default::default()
+        });
+        let transport = TonLiteTransport::with_client(server, Default::default());
+
+        let address = StdAddr::new(0, HashBytes::ZERO);
+        assert!(transport.get_contract_state(&address, None).await.is_err());
+        assert!(transport.get_config().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn unlinked_blocks_are_rejected() -> Result<()> {
+        let transport = TonLiteTransport::with_client(
+            Arc::new(CannedLiteServer::default()),
+            Default::default(),
+        );
+        let block = transport.get_last_mc_block_id().await?;
+
+        transport
+            .set_trusted_key_block(BlockId { seqno: 0, ..block })
+            .await;
+        assert!(transport.get_trusted_mc_block_id().await.is_err());
+
+        transport.set_trusted_key_block(block).await;
+        assert_eq!(transport.get_trusted_mc_block_id().await?, block);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn follows_block_links() -> Result<()> {
+        let keys = validator_keys();
+        let config = key_block_config(&keys)?;
+        let first_key_block = build_block(true, Some(&config))?;
+        let second_key_block = build_block(true, Some(&config))?;
+        let block = build_block(false, None)?;
+
+        // Links are served in separate pages, each one starts
+        // from the end of the previous one
+        let chain = |second_from: &Cell| -> Result<CannedLiteServer> {
+            Ok(CannedLiteServer {
+                last_mc_block: parking_lot::Mutex::new(Some(block_id(&block, 12))),
+                block_proofs: parking_lot::Mutex::new(
+                    [
+                        vec![BlockLink::Forward(signed_link(
+                            (&first_key_block, 10),
+                            (&second_key_block, 11),
+                            true,
+                            &keys,
+                        )?)],
+                        vec![BlockLink::Forward(signed_link(
+                            (second_from, 11),
+                            (&block, 12),
+                            false,
+                            &keys,
+                        )?)],
+                    ]
+                    .into(),
+                ),
+                ..Default::default()
+            })
+        };
+
+        let options = TonLiteTransportOptions {
+            trusted_key_block: Some(block_id(&first_key_block, 10)),
+            ..Default::default()
+        };
+        let transport =
+            TonLiteTransport::with_client(Arc::new(chain(&second_key_block)?), options.clone());
+        assert_eq!(
+            transport.get_trusted_mc_block_id().await?,
+            block_id(&block, 12)
+        );
+
+        // The second link doesn't start from the verified block
+        let transport = TonLiteTransport::with_client(Arc::new(chain(&block)?), options);
+        assert!(transport.get_trusted_mc_block_id().await.is_err());
+
+        // Lagging servers are followed back from the trusted block
+        let registered = [(block_id(&first_key_block, 10), true)];
+        let server = CannedLiteServer {
+            last_mc_block: parking_lot::Mutex::new(Some(block_id(&first_key_block, 10))),
+            block_proofs: parking_lot::Mutex::new(
+                [vec![BlockLink::Back(backward_link(
+                    (12, &registered),
+                    (&first_key_block, 10),
+                    true,
+                )?)]]
+                .into(),
+            ),
+            ..Default::default()
+        };
+        let link_from = match &server.block_proofs.lock()[0][0] {
+            BlockLink::Back(link) => link.from,
+            BlockLink::Forward(_) => unreachable!(),
+        };
+        let transport = TonLiteTransport::with_client(
+            Arc::new(server),
+            TonLiteTransportOptions {
+                trusted_key_block: Some(link_from),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            transport.get_trusted_mc_block_id().await?,
+            block_id(&first_key_block, 10)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn stale_mc_block_is_refetched() -> Result<()> {
+        let server = Arc::new(CannedLiteServer::default());
+        let transport = TonLiteTransport::with_client(
+            server.clone(),
+            TonLiteTransportOptions {
+                ping_interval: Duration::from_millis(10),
+                ..Default::default()
+            },
+        );
+
+        let new_runtime = || {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_time()
+                .build()
+        };
+
+        // The polling task is spawned on a runtime which then shuts down
+        let runtime = new_runtime()?;
+        let block = runtime.block_on(transport.get_last_mc_block_id())?;
+        drop(runtime);
+
+        let new_block = BlockId {
+            seqno: block.seqno + 1,
+            ..block
+        };
+        *server.last_mc_block.lock() = Some(new_block);
+        std::thread::sleep(Duration::from_millis(50));
+
+        let runtime = new_runtime()?;
+        assert_eq!(
+            runtime.block_on(transport.get_last_mc_block_id())?,
+            new_block
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn new_without_runtime() {
+        let transport = TonLiteTransport::with_client(
+            Arc::new(CannedLiteServer::default()),
+            Default::default(),
+        );
+        transport.shutdown();
+    }
+
+    #[tokio::test]
+    async fn mc_blocks_stream_ends_on_shutdown() -> Result<()> {
+        use futures_util::StreamExt;
+
+        let server = Arc::new(CannedLiteServer::default());
+        let transport = TonLiteTransport::with_client(server.clone(), Default::default());
+
+        let mut blocks = transport.mc_blocks();
+        let block = server.get_last_mc_block_id().await?;
+        assert_eq!(blocks.next().await, Some(block));
+
+        transport.shutdown();
+        assert_eq!(blocks.next().await, None);
+        Ok(())
+    }
+
+    fn canned_transaction(
+        lt: u64,
+        prev_trans_hash: HashBytes,
+        in_msg: Cell,
+    ) -> Result<Transaction> {
+        Ok(Transaction {
+            account: HashBytes::ZERO,
+            lt,
+            prev_trans_hash,
+            prev_trans_lt: lt - 10,
+            now: 0,
+            out_msg_count: Default::default(),
+            orig_status: AccountStatus::Active,
+            end_status: AccountStatus::Active,
+            in_msg: Some(in_msg),
+            out_msgs: Default::default(),
+            total_fees: Default::default(),
+            state_update: Lazy::new(&HashUpdate {
+                old: HashBytes::ZERO,
+                new: HashBytes::ZERO,
+            })?,
+            info: Lazy::new(&TxInfo::Ordinary(OrdinaryTxInfo {
+                credit_first: true,
+                storage_phase: None,
+                credit_phase: None,
+                compute_phase: ComputePhase::Skipped(SkippedComputePhase {
+                    reason: ComputePhaseSkipReason::NoState,
+                }),
+                action_phase: None,
+                aborted: true,
+                bounce_phase: None,
+                destroyed: false,
+            }))?,
+        })
+    }
+
+    fn canned_message(id: u32) -> Result<Cell> {
+        let mut body = CellBuilder::new();
+        body.store_u32(id)?;
+        let message =
+            MessageBuilder::new_external_in(IntAddr::Std(StdAddr::new(0, HashBytes::ZERO)))
+                .with_body(body.build()?)?
+                .build();
+        Ok(CellBuilder::build_from(&message)?)
+    }
+
+    /// Transactions with lt 30, 20 and 10 and their inbound messages.
+    /// Newest transactions go first, each one is linked to the next one.
+    fn canned_chain() -> Result<([Cell; 3], Vec<Cell>)> {
+        let messages = [canned_message(3)?, canned_message(2)?, canned_message(1)?];
+        let mut transactions = Vec::<Cell>::new();
+        for (lt, message) in [10, 20, 30].into_iter().zip(messages.iter().rev()) {
+            let prev_trans_hash = transactions
+                .last()
+                .map(|tx| *tx.repr_hash())
+                .unwrap_or_default();
+            let tx = canned_transaction(lt, prev_trans_hash, message.clone())?;
+            transactions.push(CellBuilder::build_from(tx)?);
+        }
+        transactions.reverse();
+        Ok((messages, transactions))
+    }
+
+    #[tokio::test]
+    async fn finds_dst_transaction_in_canned_list() -> Result<()> {
+        let (messages, transactions) = canned_chain()?;
+        let transport = TonLiteTransport::with_client(
+            Arc::new(CannedLiteServer {
+                transactions: transactions.clone(),
+                ..Default::default()
+            }),
+            Default::default(),
+        );
+
+        let from = LastTransactionId {
+            lt: 30,
+            hash: *transactions[0].repr_hash(),
+        };
+        let dst = MessageDestination {
+            address: StdAddr::new(0, HashBytes::ZERO),
+            min_lt: 0,
+        };
+
+        let tx = transport
+            .scan_dst_transaction(messages[1].repr_hash(), &dst, from)
+            .await?
+            .context("transaction not found")?;
+        assert_eq!(tx.lt, 20);
+
+        // Scanned transactions are cached
+        let cached = transport
+            .get_transaction(transactions[2].repr_hash())
+            .await?;
+        assert_eq!(cached.map(|tx| tx.lt), Some(10));
+
+        // Transactions older than the message are not scanned
+        let dst = MessageDestination { min_lt: 20, ..dst };
+        let found = transport
+            .scan_dst_transaction(messages[2].repr_hash(), &dst, from)
+            .await?;
+        assert!(found.is_none());
+
+        // Transactions must be linked to the requested one
+        for from in [
+            LastTransactionId {
+                hash: *transactions[1].repr_hash(),
+                ..from
+            },
+            LastTransactionId { lt: 31, ..from },
+        ] {
+            assert!(transport
+                .get_account_transactions(&dst.address, &from, TRANSACTIONS_PAGE_SIZE)
+                .await
+                .is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn account_must_be_in_shard() -> Result<()> {
+        let mc_block = BlockId {
+            shard: ShardIdent::MASTERCHAIN,
+            seqno: 1,
+            root_hash: HashBytes::ZERO,
+            file_hash: HashBytes::ZERO,
+        };
+        let mut address = HashBytes::ZERO;
+        address.0[0] = 0xc0;
+
+        let (left, right) = ShardIdent::BASECHAIN
+            .split()
+            .context("failed to split shard")?;
+        let shard_block = |shard| BlockId {
+            shard,
+            seqno: 10,
+            ..mc_block
+        };
+
+        // Basechain accounts always need a shard proof
+        let basechain = StdAddr::new(0, address);
+        assert!(check_account_shard(
+            &mc_block,
+            &shard_block(right),
+            &basechain
+        )?);
+        assert!(check_account_shard(&mc_block, &shard_block(left), &basechain).is_err());
+        assert!(check_account_shard(&mc_block, &mc_block, &basechain).is_err());
+
+        // Masterchain accounts are taken from the masterchain block only
+        let masterchain = StdAddr::new(-1, address);
+        assert!(!check_account_shard(&mc_block, &mc_block, &masterchain)?);
+        assert!(check_account_shard(&mc_block, &shard_block(right), &masterchain).is_err());
+        let old_mc_block = BlockId {
+            seqno: 0,
+            ..mc_block
+        };
+        assert!(check_account_shard(&mc_block, &old_mc_block, &masterchain).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn message_wait_is_capped() {
+        let max_wait = Duration::from_secs(300);
+        let margin = EXPIRED_MESSAGE_MARGIN.as_secs();
+
+        assert_eq!(
+            message_wait(1060, 1000, max_wait),
+            Duration::from_secs(60 + margin)
+        );
+        // Far expiration doesn't extend the wait
+        assert_eq!(message_wait(u32::MAX, 1000, max_wait), max_wait);
+        // Already expired messages
+        assert_eq!(message_wait(0, 1000, max_wait), Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn fetches_remembered_transactions() -> Result<()> {
+        let (_, transactions) = canned_chain()?;
+        let transport = TonLiteTransport::with_client(
+            Arc::new(CannedLiteServer {
+                transactions: transactions.clone(),
+                ..Default::default()
+            }),
+            Default::default(),
+        );
+
+        let hash = *transactions[1].repr_hash();
+        assert!(transport.get_transaction(&hash).await?.is_none());
+
+        let address = StdAddr::new(0, HashBytes::ZERO);
+        transport.remember_transaction(address.clone(), &LastTransactionId { lt: 20, hash });
+        let tx = transport.get_transaction(&hash).await?;
+        assert_eq!(tx.map(|tx| tx.lt), Some(20));
+
+        // Wrong lt is rejected by the chain check
+        let hash = *transactions[2].repr_hash();
+        transport.remember_transaction(address, &LastTransactionId { lt: 11, hash });
+        assert!(transport.get_transaction(&hash).await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unknown_transactions_are_not_found() -> Result<()> {
+        let transport = TonLiteTransport::with_client(
+            Arc::new(CannedLiteServer::default()),
+            Default::default(),
+        );
+
+        assert!(transport.get_transaction(&HashBytes::ZERO).await?.is_none());
+        assert!(transport
+            .get_dst_transaction(&HashBytes::ZERO)
+            .await?
+            .is_none());
+        Ok(())
+    }
+}
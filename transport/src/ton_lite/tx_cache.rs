@@ -0,0 +1,183 @@
+use std::collections::{HashMap, VecDeque};
+
+use tycho_types::cell::HashBytes;
+use tycho_types::models::{MsgInfo, OwnedMessage, StdAddr, Transaction};
+
+/// Lite servers can only look up transactions by account, so every
+/// transaction seen by the transport is remembered here together with
+/// its account and the destinations of its outgoing internal messages.
+/// All are bounded by the capacity, the oldest entries are evicted first.
+pub(crate) struct TransactionCache {
+    capacity: usize,
+    transactions: HashMap<HashBytes, Transaction>,
+    by_in_msg: HashMap<HashBytes, HashBytes>,
+    destinations: HashMap<HashBytes, MessageDestination>,
+    locations: HashMap<HashBytes, TransactionLocation>,
+    order: VecDeque<HashBytes>,
+    destinations_order: VecDeque<HashBytes>,
+    locations_order: VecDeque<HashBytes>,
+}
+
+/// Account which should receive a message, and the logical time after
+/// which the receiving transaction can appear.
+#[derive(Clone)]
+pub(crate) struct MessageDestination {
+    pub address: StdAddr,
+    pub min_lt: u64,
+}
+
+/// Account and logical time of a transaction, enough to fetch it again.
+#[derive(Clone)]
+pub(crate) struct TransactionLocation {
+    pub address: StdAddr,
+    pub lt: u64,
+}
+
+impl TransactionCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            transactions: Default::default(),
+            by_in_msg: Default::default(),
+            destinations: Default::default(),
+            order: Default::default(),
+            destinations_order: Default::default(),
+            locations: Default::default(),
+            locations_order: Default::default(),
+        }
+    }
+
+    pub fn get(&self, hash: &HashBytes) -> Option<Transaction> {
+        self.transactions.get(hash).cloned()
+    }
+
+    pub fn get_by_in_msg(&self, message_hash: &HashBytes) -> Option<Transaction> {
+        let hash = self.by_in_msg.get(message_hash)?;
+        self.get(hash)
+    }
+
+    pub fn get_location(&self, hash: &HashBytes) -> Option<TransactionLocation> {
+        self.locations.get(hash).cloned()
+    }
+
+    pub fn remember_location(&mut self, hash: HashBytes, location: TransactionLocation) {
+        self.insert_location(hash, location);
+        self.evict();
+    }
+
+    fn insert_location(&mut self, hash: HashBytes, location: TransactionLocation) {
+        if self.locations.insert(hash, location).is_none() {
+            self.locations_order.push_back(hash);
+        }
+    }
+
+    pub fn get_destination(&self, message_hash: &HashBytes) -> Option<MessageDestination> {
+        self.destinations.get(message_hash).cloned()
+    }
+
+    pub fn remember_destination(&mut self, message_hash: HashBytes, dst: MessageDestination) {
+        self.insert_destination(message_hash, dst);
+        self.evict();
+    }
+
+    fn insert_destination(&mut self, message_hash: HashBytes, dst: MessageDestination) {
+        if self.destinations.insert(message_hash, dst).is_none() {
+            self.destinations_order.push_back(message_hash);
+        }
+    }
+
+    pub fn insert(&mut self, hash: HashBytes, address: &StdAddr, tx: &Transaction) {
+        if self.transactions.contains_key(&hash) {
+            return;
+        }
+
+        self.insert_location(
+            hash,
+            TransactionLocation {
+                address: address.clone(),
+                lt: tx.lt,
+            },
+        );
+
+        if let Some(in_msg) = &tx.in_msg {
+            let in_msg_hash = *in_msg.repr_hash();
+            self.by_in_msg.insert(in_msg_hash, hash);
+            self.destinations.remove(&in_msg_hash);
+        }
+
+        for item in tx.out_msgs.values() {
+            let Ok(cell) = item else {
+                continue;
+            };
+            let Ok(message) = cell.parse::<OwnedMessage>() else {
+                continue;
+            };
+            if let MsgInfo::Int(info) = &message.info {
+                if let Some(address) = info.dst.as_std() {
+                    self.insert_destination(
+                        *cell.repr_hash(),
+                        MessageDestination {
+                            address: address.clone(),
+                            min_lt: info.created_lt,
+                        },
+                    );
+                }
+            }
+        }
+
+        self.transactions.insert(hash, tx.clone());
+        self.order.push_back(hash);
+        self.evict();
+    }
+
+    fn evict(&mut self) {
+        while self.order.len() > self.capacity {
+            let Some(hash) = self.order.pop_front() else {
+                break;
+            };
+            if let Some(tx) = self.transactions.remove(&hash) {
+                if let Some(in_msg) = &tx.in_msg {
+                    self.by_in_msg.remove(in_msg.repr_hash());
+                }
+            }
+        }
+
+        // Order may contain hashes of already delivered messages,
+        // so the map never outgrows it
+        while self.destinations_order.len() > self.capacity {
+            let Some(hash) = self.destinations_order.pop_front() else {
+                break;
+            };
+            self.destinations.remove(&hash);
+        }
+
+        while self.locations_order.len() > self.capacity {
+            let Some(hash) = self.locations_order.pop_front() else {
+                break;
+            };
+            self.locations.remove(&hash);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn destinations_are_bounded() {
+        let mut cache = TransactionCache::new(2);
+        let dst = MessageDestination {
+            address: StdAddr::new(0, HashBytes::ZERO),
+            min_lt: 0,
+        };
+
+        for i in 0..4 {
+            cache.remember_destination(HashBytes([i; 32]), dst.clone());
+        }
+
+        assert_eq!(cache.destinations.len(), 2);
+        assert!(cache.get_destination(&HashBytes([1; 32])).is_none());
+        assert!(cache.get_destination(&HashBytes([3; 32])).is_some());
+    }
+}
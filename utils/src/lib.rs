@@ -1,4 +1,5 @@
 pub mod serde_helpers;
 pub mod signature_context;
+pub mod signer;
 pub mod time;
 pub mod traits;
@@ -0,0 +1,20 @@
+use ed25519_dalek::{Signature, SigningKey, VerifyingKey};
+
+use crate::signature_context::SignatureContext;
+
+/// Source of signatures for external messages.
+pub trait Signer: Send + Sync {
+    fn public_key(&self) -> VerifyingKey;
+
+    fn sign(&self, data: &[u8], ctx: SignatureContext) -> Signature;
+}
+
+impl Signer for SigningKey {
+    fn public_key(&self) -> VerifyingKey {
+        self.verifying_key()
+    }
+
+    fn sign(&self, data: &[u8], ctx: SignatureContext) -> Signature {
+        ctx.sign(self, data)
+    }
+}